@@ -314,6 +314,195 @@ variation! {
 
 mod extra
 {
+    /// Like `robust` but with the table that stores the classes contiguously, to compare with
+    /// the table of shared-ownership classes of `robust`.
+    #[cfg(feature = "std")]
+    pub mod arena_table
+    {
+        use {
+            core::{
+                convert::Infallible,
+                marker::PhantomData,
+            },
+            graph_safe_compare::{
+                cycle_safe::modes::interleave::{
+                    self,
+                    random::default,
+                    Interleave,
+                },
+                deep_safe::recursion::{
+                    self,
+                    queue::RecurQueue,
+                },
+                generic::{
+                    equiv::{
+                        self,
+                        Equiv,
+                    },
+                    equiv_classes::premade::arena,
+                },
+                Node,
+            },
+        };
+
+        struct Args<N>(PhantomData<N>);
+
+        impl<N: Node> equiv::Params for Args<N>
+        {
+            type DescendMode = Interleave<Self>;
+            type Error = Infallible;
+            type Node = N;
+            type RecurMode = RecurQueue<Self>;
+        }
+
+        impl<N: Node> interleave::Params for Args<N>
+        {
+            type Node = N;
+            type RNG = default::RandomNumberGenerator;
+            type Table = arena::Table<Self>;
+        }
+
+        impl<N: Node> arena::Params for Args<N>
+        {
+            type Node = N;
+        }
+
+        impl<N: Node> recursion::queue::Params for Args<N>
+        {
+            type Node = N;
+        }
+
+        pub fn equiv<N: Node>(
+            a: N,
+            b: N,
+        ) -> Result<N::Cmp, Infallible>
+        {
+            Equiv::<Args<N>>::default().equiv(a, b)
+        }
+    }
+
+    /// Like `robust` but with the mode that adapts its limits, to compare with the fixed limits
+    /// of the "interleave" mode of `robust`.
+    #[cfg(feature = "std")]
+    pub mod adaptive
+    {
+        use {
+            core::{
+                convert::Infallible,
+                marker::PhantomData,
+            },
+            graph_safe_compare::{
+                cycle_safe::modes::{
+                    adaptive::{
+                        self,
+                        Adaptive,
+                    },
+                    interleave::{
+                        self,
+                        random::default,
+                    },
+                },
+                deep_safe::recursion::{
+                    self,
+                    queue::RecurQueue,
+                },
+                generic::{
+                    equiv::{
+                        self,
+                        Equiv,
+                    },
+                    equiv_classes::premade::hash_map,
+                },
+                Node,
+            },
+        };
+
+        struct Args<N>(PhantomData<N>);
+
+        impl<N: Node> equiv::Params for Args<N>
+        {
+            type DescendMode = Adaptive<Self>;
+            type Error = Infallible;
+            type Node = N;
+            type RecurMode = RecurQueue<Self>;
+        }
+
+        impl<N: Node> interleave::Params for Args<N>
+        {
+            type Node = N;
+            type RNG = default::RandomNumberGenerator;
+            type Table = hash_map::Table<Self>;
+        }
+
+        impl<N: Node> adaptive::Params for Args<N> {}
+
+        impl<N: Node> hash_map::Params for Args<N>
+        {
+            type Node = N;
+        }
+
+        impl<N: Node> recursion::queue::Params for Args<N>
+        {
+            type Node = N;
+        }
+
+        pub fn equiv<N: Node>(
+            a: N,
+            b: N,
+        ) -> Result<N::Cmp, Infallible>
+        {
+            Equiv::<Args<N>>::default().equiv(a, b)
+        }
+    }
+
+    /// Like `wide_safe` but with the recursion mode that uses the call-stack up to a limited
+    /// depth, to compare with the vector-stack of `wide_safe` and with the call-stack of `basic`.
+    #[cfg(feature = "alloc")]
+    pub mod hybrid
+    {
+        use {
+            core::{
+                convert::Infallible,
+                marker::PhantomData,
+            },
+            graph_safe_compare::{
+                basic::modes::unlimited::Unlimited,
+                generic::equiv::{
+                    self,
+                    Equiv,
+                },
+                wide_safe::recursion::hybrid::{
+                    self,
+                    Hybrid,
+                },
+                Node,
+            },
+        };
+
+        struct Args<N>(PhantomData<N>);
+
+        impl<N: Node> equiv::Params for Args<N>
+        {
+            type DescendMode = Unlimited;
+            type Error = Infallible;
+            type Node = N;
+            type RecurMode = Hybrid<Self>;
+        }
+
+        impl<N: Node> hybrid::Params for Args<N>
+        {
+            type Node = N;
+        }
+
+        pub fn equiv<N: Node>(
+            a: N,
+            b: N,
+        ) -> Result<N::Cmp, Infallible>
+        {
+            Equiv::<Args<N>>::default().equiv(a, b)
+        }
+    }
+
     pub mod derived_eq
     {
         use crate::common::borrow_pair::{
@@ -346,3 +535,62 @@ variation! {
     ],
     [eq]
 }
+
+#[cfg(feature = "std")]
+variation! {
+    arena_table,
+    crate::extra::arena_table,
+    [
+        list,
+        inverted_list,
+        degenerate_dag,
+        degenerate_cyclic,
+        long_list,
+        long_inverted_list,
+        long_degenerate_dag,
+        long_degenerate_cyclic,
+        short_list,
+        short_inverted_list,
+        short_degenerate_dag,
+        short_degenerate_cyclic
+    ],
+    [equiv]
+}
+
+#[cfg(feature = "std")]
+variation! {
+    adaptive,
+    crate::extra::adaptive,
+    [
+        list,
+        inverted_list,
+        degenerate_dag,
+        degenerate_cyclic,
+        long_list,
+        long_inverted_list,
+        long_degenerate_dag,
+        long_degenerate_cyclic,
+        short_list,
+        short_inverted_list,
+        short_degenerate_dag,
+        short_degenerate_cyclic
+    ],
+    [equiv]
+}
+
+#[cfg(feature = "alloc")]
+variation! {
+    hybrid,
+    crate::extra::hybrid,
+    [
+        list,
+        inverted_list,
+        degenerate_dag,
+        long_list,
+        long_inverted_list,
+        short_list,
+        short_inverted_list,
+        short_degenerate_dag
+    ],
+    [equiv]
+}
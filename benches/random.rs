@@ -1,7 +1,4 @@
-#![cfg(all(
-    any(rust_lib_feature = "test", rust_comp_feature = "unstable_features"),
-    any(feature = "fastrand", feature = "oorandom", feature = "wyrng")
-))]
+#![cfg(any(rust_lib_feature = "test", rust_comp_feature = "unstable_features"))]
 #![cfg_attr(not(rust_lib_feature = "test"), feature(test))]
 
 extern crate test;
@@ -49,6 +46,21 @@ mod oorandom
     }
 }
 
+mod splitmix
+{
+    use {
+        super::*,
+        graph_safe_compare::cycle_safe::modes::interleave::random::splitmix,
+    };
+
+    #[bench]
+    fn rand_upto(bencher: &mut Bencher)
+    {
+        let mut rng = splitmix::RandomNumberGenerator::default();
+        bencher.iter(|| rng.rand_upto(END))
+    }
+}
+
 #[cfg(feature = "wyrng")]
 mod wyrng
 {
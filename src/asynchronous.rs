@@ -0,0 +1,307 @@
+//! Comparison of graphs whose nodes are accessed asynchronously, e.g. graphs that are fetched on
+//! demand from a storage layer where getting edges must be awaited.
+//!
+//! This is the same algorithm as the other variations, where the loop of
+//! [`Equiv::equiv`](crate::generic::equiv::Equiv::equiv) is translated to an `async fn`.  The
+//! pending edges are kept in a `VecDeque`, used as a stack or as a queue, and so the futures do
+//! not grow with the depth of the graphs.  The existing [`DescendMode`]s can be used, via
+//! [`IdOf`](crate::asynchronous::IdOf), because they only use the IDs of nodes.
+//!
+//! The existing recursion modes cannot be reused for the pending edges, because they hold the
+//! synchronous `EdgesIter`, which gets the next edges by calling [`Node::get_edge`] within
+//! [`Iterator::next`], whereas here getting each edge must be awaited, and because the
+//! [`RecurMode`] interface takes the synchronous `Equiv` and supplies the next counterpart nodes
+//! without awaiting.
+//!
+//! Only [`core::future`] is used, and so any executor can be used to run the futures.
+//!
+//! [`DescendMode`]: crate::generic::equiv::DescendMode
+//! [`RecurMode`]: crate::generic::equiv::RecurMode
+
+extern crate alloc;
+
+use {
+    crate::{
+        cycle_safe::modes::interleave::{
+            self,
+            random::default,
+            Interleave,
+        },
+        generic::{
+            equiv::Composable,
+            equiv_classes::premade::DefaultTable,
+            increment_index,
+        },
+        utils::Order,
+        Cmp,
+        Node,
+        Step,
+    },
+    alloc::collections::VecDeque,
+    core::{
+        cmp::Ordering,
+        future::Future,
+        hash::Hash,
+        marker::PhantomData,
+    },
+};
+
+#[cfg(not(feature = "anticipate"))]
+use crate::like_anticipated::IntoOk as _;
+
+
+/// Like [`Node`] but getting edges and comparing nodes give futures.
+///
+/// The same rules as for [`Node`] apply to the values that the futures give.
+pub trait AsyncNode: Sized
+{
+    /// Like [`Node::Cmp`].
+    type Cmp: Cmp;
+    /// Like [`Node::Id`].
+    type Id: Eq + Hash + Clone;
+    /// Like [`Node::Index`].
+    type Index: Step + Default + Ord;
+    /// Future of [`Self::get_edge`].  It cannot borrow `self`, and so it usually owns what it
+    /// needs, e.g. a clone of a handle to the storage.
+    type EdgeFuture: Future<Output = Option<Self>>;
+    /// Future of [`Self::equiv_modulo_edges`].  It cannot borrow the nodes, similarly.
+    type CmpFuture: Future<Output = Self::Cmp>;
+
+    /// Like [`Node::id`], which is not asynchronous because the ID must be known without
+    /// fetching.
+    fn id(&self) -> Self::Id;
+
+    /// Like [`Node::get_edge`].
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Self::EdgeFuture;
+
+    /// Like [`Node::equiv_modulo_edges`].
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::CmpFuture;
+}
+
+
+/// The ID of an [`AsyncNode`], as a [`Node`] that has no edges.
+///
+/// Enables using the [`Composable`] modes (e.g. [`Interleave`]) with [`AsyncEquiv`], because
+/// those only use the IDs of the nodes that they are given.
+#[allow(clippy::exhaustive_structs)]
+pub struct IdOf<N: AsyncNode>(pub N::Id);
+
+impl<N: AsyncNode> Node for IdOf<N>
+{
+    type Cmp = N::Cmp;
+    type Id = N::Id;
+    type Index = N::Index;
+
+    #[inline]
+    fn id(&self) -> Self::Id
+    {
+        self.0.clone()
+    }
+
+    #[inline]
+    fn get_edge(
+        &self,
+        _index: &Self::Index,
+    ) -> Option<Self>
+    {
+        None
+    }
+
+    #[inline]
+    fn equiv_modulo_edges(
+        &self,
+        _other: &Self,
+    ) -> Self::Cmp
+    {
+        Cmp::new_equiv()
+    }
+}
+
+
+/// Compare the graphs rooted at `a` and `b`, asynchronously.
+///
+/// Safe for cyclic, degenerate, and very-deep graphs, like
+/// [`robust::equiv`](crate::robust::equiv), by using the [`Interleave`] mode and the
+/// breadth-first order.
+#[inline]
+pub async fn equiv<N>(
+    a: N,
+    b: N,
+) -> N::Cmp
+where
+    N: AsyncNode,
+    IdOf<N>: DefaultTable,
+{
+    struct Args<N>(PhantomData<N>);
+
+    impl<N: AsyncNode> interleave::Params for Args<N>
+    where IdOf<N>: DefaultTable
+    {
+        type Node = IdOf<N>;
+        type RNG = default::RandomNumberGenerator;
+        type Table = <IdOf<N> as DefaultTable>::Table;
+    }
+
+    let mut e = AsyncEquiv::new(Interleave::<Args<N>>::default(), Order::BreadthFirst);
+    #[allow(unstable_name_collisions)]
+    e.equiv(a, b).await.into_ok()
+}
+
+
+/// The asynchronous driver of the algorithm, like [`Equiv`](crate::generic::equiv::Equiv) but
+/// for [`AsyncNode`]s.
+///
+/// The descend mode `M` is used with the [`IdOf`] of the nodes.  The [`Order`] determines whether
+/// the pending edges are a stack, for depth-first, or a queue, for breadth-first.
+pub struct AsyncEquiv<N: AsyncNode, M>
+{
+    /// Controls whether nodes are traversed and whether their edges are descended.
+    descend_mode: M,
+    /// The order of descending edges.
+    order:        Order,
+    /// Counterpart nodes whose edges remain to be compared.
+    pending:      VecDeque<Edges<N>>,
+}
+
+/// Counterpart nodes whose edges are compared, like `EdgesIter` of the synchronous algorithm.
+struct Edges<N: AsyncNode>
+{
+    counterparts: [N; 2],
+    /// Index of the next edges to get, or `None` if the index cannot be incremented.
+    next_index:   Option<N::Index>,
+    /// The first edges, which are gotten when the counterparts are compared, to check whether
+    /// they both have edges.
+    first:        Option<[N; 2]>,
+}
+
+impl<N, M> AsyncEquiv<N, M>
+where
+    N: AsyncNode,
+    M: Composable<IdOf<N>>,
+{
+    /// Create a new instance that uses the given descend mode and order.
+    #[inline]
+    pub fn new(
+        descend_mode: M,
+        order: Order,
+    ) -> Self
+    {
+        Self { descend_mode, order, pending: VecDeque::new() }
+    }
+
+    /// Reset to a fresh state, for reuse in an independent invocation, while preserving
+    /// capacity.
+    #[inline]
+    pub fn reset(&mut self)
+    {
+        self.descend_mode.reset();
+        self.pending.clear();
+    }
+
+    /// Like [`Equiv::equiv`](crate::generic::equiv::Equiv::equiv) but awaits getting the edges
+    /// and comparing the nodes.
+    ///
+    /// # Errors
+    /// If the descend mode errors, returns the error.
+    #[inline]
+    pub async fn equiv(
+        &mut self,
+        mut a: N,
+        mut b: N,
+    ) -> Result<N::Cmp, M::Error>
+    {
+        // Any pending edges are from a previous comparison that did not finish.
+        self.pending.clear();
+
+        // This loop, with the pending edges, is what prevents nesting futures when traversing
+        // descendents, like the loop of the synchronous algorithm prevents growing the
+        // call-stack.
+        loop {
+            let cmp = self.equiv_main(a, b).await?;
+            if !cmp.is_equiv() {
+                break Ok(cmp);
+            }
+            match self.next().await {
+                Some(Ok([an, bn])) => {
+                    a = an;
+                    b = bn;
+                },
+                Some(Err(cmp_amount_edges)) => break Ok(cmp_amount_edges),
+                None => break Ok(cmp),
+            }
+        }
+    }
+
+    /// Compare the nodes themselves, and add their edges to the pending if they have any and
+    /// if the descend mode says to.
+    async fn equiv_main(
+        &mut self,
+        a: N,
+        b: N,
+    ) -> Result<N::Cmp, M::Error>
+    {
+        let mut cmp = Cmp::new_equiv();
+
+        if self.descend_mode.do_traverse()? && a.id() != b.id() {
+            cmp = a.equiv_modulo_edges(&b).await;
+            if cmp.is_equiv() {
+                let index = N::Index::default();
+                match (a.get_edge(&index).await, b.get_edge(&index).await) {
+                    (Some(ae), Some(be)) =>
+                        if self.descend_mode.do_edges(&IdOf(a.id()), &IdOf(b.id()))? {
+                            self.pending.push_back(Edges {
+                                next_index:   increment_index(&index),
+                                counterparts: [a, b],
+                                first:        Some([ae, be]),
+                            });
+                        },
+                    (None, None) => {},
+                    (None, Some(_)) => cmp = Cmp::from_ord(Ordering::Less),
+                    (Some(_), None) => cmp = Cmp::from_ord(Ordering::Greater),
+                }
+            }
+        }
+        Ok(cmp)
+    }
+
+    /// Get the next counterpart edges to compare, from the end of the pending that is
+    /// determined by the order.  Or give the comparison of the amounts of edges if different.
+    async fn next(&mut self) -> Option<Result<[N; 2], N::Cmp>>
+    {
+        loop {
+            let edges = match self.order {
+                Order::DepthFirst => self.pending.back_mut(),
+                Order::BreadthFirst => self.pending.front_mut(),
+            }?;
+            if let Some(first) = edges.first.take() {
+                break Some(Ok(first));
+            }
+            let gotten = if let Some(index) = edges.next_index.take() {
+                let [a, b] = &edges.counterparts;
+                let gotten = (a.get_edge(&index).await, b.get_edge(&index).await);
+                edges.next_index = increment_index(&index);
+                gotten
+            }
+            else {
+                (None, None)
+            };
+            match gotten {
+                (Some(ae), Some(be)) => break Some(Ok([ae, be])),
+                (None, None) => {
+                    let _exhausted = match self.order {
+                        Order::DepthFirst => self.pending.pop_back(),
+                        Order::BreadthFirst => self.pending.pop_front(),
+                    };
+                },
+                (None, Some(_)) => break Some(Err(Cmp::from_ord(Ordering::Less))),
+                (Some(_), None) => break Some(Err(Cmp::from_ord(Ordering::Greater))),
+            }
+        }
+    }
+}
@@ -160,10 +160,8 @@ pub mod modes
     {
         use crate::{
             anticipated_or_like::Infallible,
-            generic::equiv::{
-                self,
-                DescendMode,
-            },
+            generic::equiv::Composable,
+            Node,
         };
 
         /// Specifies not limiting the amount of nodes traversed.
@@ -172,10 +170,7 @@ pub mod modes
         pub struct Unlimited;
 
         /// Enables [`Unlimited`] to be used with the algorithm.
-        impl<P> DescendMode<P> for Unlimited
-        where
-            P: equiv::Params<DescendMode = Self>,
-            Infallible: Into<P::Error>,
+        impl<N: Node> Composable<N> for Unlimited
         {
             type Error = Infallible;
 
@@ -183,8 +178,8 @@ pub mod modes
             #[inline]
             fn do_edges(
                 &mut self,
-                _a: &P::Node,
-                _b: &P::Node,
+                _a: &N,
+                _b: &N,
             ) -> Result<bool, Self::Error>
             {
                 Ok(true)
@@ -220,10 +215,8 @@ pub mod modes
 
         use crate::{
             anticipated_or_like::Infallible,
-            generic::equiv::{
-                self,
-                DescendMode,
-            },
+            generic::equiv::Composable,
+            Node,
         };
 
         /// Specifies limiting the amount of nodes traversed.  The inner value is the limit.
@@ -231,7 +224,7 @@ pub mod modes
         pub struct Limited<T>(pub T);
 
         /// [`Err`] type returned when aborting early because a limit was reached.
-        #[derive(Debug)]
+        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
         #[allow(clippy::exhaustive_structs)]
         pub struct LimitReached;
 
@@ -248,11 +241,7 @@ pub mod modes
         }
 
         /// Enables [`Limited`] to be used with the algorithm.
-        impl<T, P> DescendMode<P> for Limited<T>
-        where
-            T: Ticker,
-            P: equiv::Params<DescendMode = Self>,
-            LimitReached: Into<P::Error>,
+        impl<T: Ticker, N: Node> Composable<N> for Limited<T>
         {
             type Error = LimitReached;
 
@@ -260,8 +249,8 @@ pub mod modes
             #[inline]
             fn do_edges(
                 &mut self,
-                _a: &P::Node,
-                _b: &P::Node,
+                _a: &N,
+                _b: &N,
             ) -> Result<bool, Self::Error>
             {
                 Ok(true)
@@ -0,0 +1,166 @@
+use {
+    crate::{
+        Cmp,
+        Node,
+    },
+    core::cmp::Ordering,
+};
+
+
+/// What the algorithm requires from a pair of types, to compare nodes of the `Self` type with
+/// nodes of the `Other` type.
+///
+/// Both types must use the same [`Node::Cmp`] and [`Node::Index`] types, so that the results and
+/// the edges of counterpart nodes correspond.  Their [`Node::Id`] types may differ, and the
+/// identities of each side are kept separate (see [`CrossId`]).
+#[allow(clippy::module_name_repetitions)]
+pub trait CrossNode<Other>: Node
+where Other: Node<Cmp = Self::Cmp, Index = Self::Index>
+{
+    /// Like [`Node::equiv_modulo_edges`] but where the `other` node is of the `Other` type.
+    ///
+    /// Should give the same result as [`Node::equiv_modulo_edges`] would if the `other` node
+    /// were converted to the `Self` type.
+    fn cross_equiv_modulo_edges(
+        &self,
+        other: &Other,
+    ) -> Self::Cmp;
+}
+
+
+/// A node of either side of a comparison between graphs of two different node types, e.g. an
+/// in-memory representation versus a view of a serialized form of it.
+///
+/// Adapts a pair of node types, that together `impl` [`CrossNode`], into a single [`Node`] type,
+/// and so it can be used with any of the variations of the algorithm and with any of the
+/// recursion modes.  The `A` variant must be used for the first argument and the `B` variant for
+/// the second argument, when invoking the algorithm.  E.g.:
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use graph_safe_compare::{cross::{Cross, CrossNode}, robust, utils::RefId, Node};
+///
+/// struct Tree(i32, Vec<Tree>);
+///
+/// /// Flattened form of a `Tree`, where edges are indexes of other elements.
+/// struct Flat(Vec<(i32, Vec<usize>)>);
+///
+/// #[derive(Clone, Copy)]
+/// struct FlatNode<'l>(&'l Flat, usize);
+///
+/// impl<'l> Node for &'l Tree {
+///     type Cmp = bool;
+///     type Id = RefId<Self>;
+///     type Index = usize;
+///
+///     fn id(&self) -> Self::Id { RefId(*self) }
+///
+///     fn get_edge(&self, index: &Self::Index) -> Option<Self> { self.1.get(*index) }
+///
+///     fn equiv_modulo_edges(&self, other: &Self) -> bool { self.0 == other.0 }
+/// }
+///
+/// impl<'l> Node for FlatNode<'l> {
+///     type Cmp = bool;
+///     type Id = usize;
+///     type Index = usize;
+///
+///     fn id(&self) -> Self::Id { self.1 }
+///
+///     fn get_edge(&self, index: &Self::Index) -> Option<Self> {
+///         self.0.0[self.1].1.get(*index).map(|&i| FlatNode(self.0, i))
+///     }
+///
+///     fn equiv_modulo_edges(&self, other: &Self) -> bool {
+///         self.0.0[self.1].0 == other.0.0[other.1].0
+///     }
+/// }
+///
+/// impl<'t, 'f> CrossNode<FlatNode<'f>> for &'t Tree {
+///     fn cross_equiv_modulo_edges(&self, other: &FlatNode<'f>) -> bool {
+///         self.0 == other.0.0[other.1].0
+///     }
+/// }
+///
+/// let tree = Tree(1, vec![Tree(2, vec![]), Tree(3, vec![])]);
+/// let flat = Flat(vec![(1, vec![1, 2]), (2, vec![]), (3, vec![])]);
+///
+/// assert!(robust::equiv(Cross::A(&tree), Cross::B(FlatNode(&flat, 0))));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum Cross<A, B>
+{
+    /// A node of the first graph.
+    A(A),
+    /// A node of the second graph.
+    B(B),
+}
+
+/// The identity of a [`Cross`] node.
+///
+/// Keeps the identities of the nodes of each side in separate namespaces, so that nodes of the
+/// `A` side are never considered identical to nodes of the `B` side, even if their inner
+/// identities happen to be `==` (e.g. when both are indexes), and so that the equivalence classes
+/// recorded for the two sides cannot be confused.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[allow(clippy::exhaustive_enums, clippy::module_name_repetitions)]
+pub enum CrossId<A, B>
+{
+    /// Identity of a node of the first graph.
+    A(A),
+    /// Identity of a node of the second graph.
+    B(B),
+}
+
+impl<A, B> Node for Cross<A, B>
+where
+    A: CrossNode<B>,
+    B: Node<Cmp = A::Cmp, Index = A::Index>,
+{
+    type Cmp = A::Cmp;
+    type Id = CrossId<A::Id, B::Id>;
+    type Index = A::Index;
+
+    #[inline]
+    fn id(&self) -> Self::Id
+    {
+        match self {
+            Cross::A(a) => CrossId::A(a.id()),
+            Cross::B(b) => CrossId::B(b.id()),
+        }
+    }
+
+    /// The edges of each side stay on the same side.
+    #[inline]
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        match self {
+            Cross::A(a) => a.get_edge(index).map(Cross::A),
+            Cross::B(b) => b.get_edge(index).map(Cross::B),
+        }
+    }
+
+    /// Uses [`CrossNode::cross_equiv_modulo_edges`] when `self` is of the `A` side and `other` is
+    /// of the `B` side, which is always the case when the algorithm is invoked as intended
+    /// (because counterparts always keep the same order as the initial arguments).  The other
+    /// combinations are still well-defined: same-side nodes are compared by their own
+    /// [`Node::equiv_modulo_edges`], and reversed sides are considered inequivalent.
+    #[inline]
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        match (self, other) {
+            (Cross::A(a), Cross::B(b)) => a.cross_equiv_modulo_edges(b),
+            (Cross::A(a1), Cross::A(a2)) => a1.equiv_modulo_edges(a2),
+            (Cross::B(b1), Cross::B(b2)) => b1.equiv_modulo_edges(b2),
+            (Cross::B(_), Cross::A(_)) => Cmp::from_ord(Ordering::Greater),
+        }
+    }
+}
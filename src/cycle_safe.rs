@@ -1,13 +1,17 @@
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub use premade::*;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 mod premade
 {
     use {
         super::modes::interleave::{
             self,
-            random::default,
+            random::{
+                default,
+                NumberGenerator,
+                SeedableNumberGenerator,
+            },
             Interleave,
         },
         crate::{
@@ -18,10 +22,9 @@ mod premade
                     self,
                     Equiv,
                 },
-                equiv_classes::premade::hash_map,
+                equiv_classes::premade::DefaultTable,
                 precheck_interleave,
             },
-            Node,
         },
         core::marker::PhantomData,
     };
@@ -29,28 +32,28 @@ mod premade
     #[cfg(not(feature = "anticipate"))]
     use crate::like_anticipated::IntoOk as _;
 
-    struct Args<N>(PhantomData<N>);
+    struct Args<N, R = default::RandomNumberGenerator>(PhantomData<(N, R)>);
 
-    impl<N: Node> interleave::Params for Args<N>
-    {
-        type Node = N;
-        type RNG = default::RandomNumberGenerator;
-        type Table = hash_map::Table<Self>;
-    }
+    /// Like `Args` but with an RNG that can be seeded, which the default might not be.
+    type SeededArgs<N> = Args<N, default::SeededRandomNumberGenerator>;
 
-    impl<N: Node> hash_map::Params for Args<N>
+    impl<N: DefaultTable, R: NumberGenerator> interleave::Params for Args<N, R>
     {
         type Node = N;
+        type RNG = R;
+        type Table = N::Table;
     }
 
     /// Equivalence predicate that can handle cyclic graphs but not very-deep graphs.
+    ///
+    /// Without the "std" feature, requires the node IDs to be [`Ord`] (see [`DefaultTable`]).
     #[inline]
-    pub fn equiv<N: Node>(
+    pub fn equiv<N: DefaultTable>(
         a: N,
         b: N,
     ) -> N::Cmp
     {
-        impl<N: Node> equiv::Params for Args<N>
+        impl<N: DefaultTable, R: NumberGenerator> equiv::Params for Args<N, R>
         {
             type DescendMode = Interleave<Self>;
             type Error = Infallible;
@@ -64,15 +67,32 @@ mod premade
     }
 
 
+    /// Like [`equiv`](equiv()) but uses an RNG created from `seed`, like
+    /// [`robust::equiv_seeded`](crate::robust::equiv_seeded).
+    #[inline]
+    pub fn equiv_seeded<N: DefaultTable>(
+        seed: u64,
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        let mut e = Equiv::<SeededArgs<N>>::new(
+            Interleave::default().with_rng(SeedableNumberGenerator::from_seed(seed)),
+        );
+        #[allow(unstable_name_collisions)]
+        e.equiv(a, b).into_ok()
+    }
+
+
     /// Like [`equiv`](equiv()) but first tries the precheck that is faster for small acyclic
     /// graphs.
     #[inline]
-    pub fn precheck_equiv<N: Node + Clone>(
+    pub fn precheck_equiv<N: DefaultTable + Clone>(
         a: N,
         b: N,
     ) -> N::Cmp
     {
-        impl<N: Node> precheck_interleave::Params<N> for Args<N>
+        impl<N: DefaultTable, R: NumberGenerator> precheck_interleave::Params<N> for Args<N, R>
         {
             type Error = Infallible;
             type InterleaveParams = Self;
@@ -83,12 +103,27 @@ mod premade
         #[allow(unstable_name_collisions)]
         precheck_interleave::equiv::<N, Args<N>>(a, b).into_ok()
     }
+
+
+    /// Like [`precheck_equiv`] but uses an RNG created from `seed`, like [`equiv_seeded`].
+    #[inline]
+    pub fn precheck_equiv_seeded<N: DefaultTable + Clone>(
+        seed: u64,
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        #[allow(unstable_name_collisions)]
+        precheck_interleave::equiv_seeded::<N, SeededArgs<N>>(seed, a, b).into_ok()
+    }
 }
 
 
 /// Modes of the algorithm that enable handling cyclic and degenerate graphs.
 pub mod modes
 {
+    pub mod adaptive;
+
     /// Make the algorithm interleave a shared-structure-detecting "slow" phase with a basic
     /// "fast" phase.
     pub mod interleave
@@ -99,15 +134,13 @@ pub mod modes
 
         use {
             crate::{
-                anticipated_or_like::Infallible,
                 generic::{
-                    equiv::{
-                        self,
-                        DescendMode,
-                    },
+                    equiv::Composable,
                     equiv_classes::{
+                        premade,
                         EquivClasses,
-                        Table,
+                        UnionFind,
+                        WithCapacity,
                     },
                 },
                 Node,
@@ -117,6 +150,25 @@ pub mod modes
         };
 
 
+        /// Default of [`Params::PRECHECK_LIMIT`] and of [`Config::precheck_limit`].
+        const DEFAULT_PRECHECK_LIMIT: u16 = 400;
+
+        /// Default of [`Params::FAST_LIMIT_MAX`] and of [`Config::fast_limit_max`], derived from
+        /// the precheck limit.
+        const fn default_fast_limit_max(precheck_limit: u16) -> u16
+        {
+            precheck_limit.saturating_mul(2)
+        }
+
+        /// Default of [`Params::SLOW_LIMIT`] and of [`Config::slow_limit`], derived from the
+        /// precheck limit.
+        // Rounding down is intended.
+        #[allow(clippy::integer_division, clippy::integer_division_remainder_used)]
+        const fn default_slow_limit(precheck_limit: u16) -> u16
+        {
+            precheck_limit / 10
+        }
+
         /// Generic parameters of [`Interleave`] and its operations.
         ///
         /// The default values for the associated constants are from the paper, which is for
@@ -132,35 +184,96 @@ pub mod modes
             /// paper.  Included here in [`Params`] because it often makes sense for the other
             /// constants to be defined in terms of it.  You may redefine only this and the others
             /// will be derived from that, or you may redefine the others.
-            const PRECHECK_LIMIT: u16 = 400;
+            const PRECHECK_LIMIT: u16 = DEFAULT_PRECHECK_LIMIT;
             /// Maximum of randomized limiting of how many nodes are traversed by the "fast" phase
             /// before switching to the "slow" phase.
-            const FAST_LIMIT_MAX: u16 = 2 * Self::PRECHECK_LIMIT;
+            const FAST_LIMIT_MAX: u16 = default_fast_limit_max(Self::PRECHECK_LIMIT);
             /// How many nodes, consecutively, that have not already been seen, are traversed by
             /// the "slow" phase before switching to the "fast" phase.
-            #[allow(clippy::integer_division)]
-            const SLOW_LIMIT: u16 = Self::PRECHECK_LIMIT / 10;
+            const SLOW_LIMIT: u16 = default_slow_limit(Self::PRECHECK_LIMIT);
 
             /// Type of node that is recorded as equivalent in the [`Self::Table`].  Must be the
-            /// same as used with the corresponding [`equiv::Params`].
+            /// same as used with the corresponding
+            /// [`equiv::Params`](crate::generic::equiv::Params).
             type Node: Node;
-            /// Type that records nodes as equivalent.
-            type Table: Table<Node = Self::Node>;
+            /// Type that records nodes as equivalent.  Either a
+            /// [`Table`](crate::generic::equiv_classes::Table) of shared-ownership classes (e.g.
+            /// the `hash_map::Table` of [`premade`]), or another representation of classes (e.g.
+            /// the `arena::Table`).
+            type Table: UnionFind<Node = Self::Node>;
             /// Type that provides a sequence of (pseudo)random numbers, used to vary the limit of
             /// the "fast" phase.
             type RNG: random::NumberGenerator;
         }
 
+        /// Values of the limits of [`Interleave`] and of the capacity of its table, that are
+        /// chosen at run-time.
+        ///
+        /// Alternative to the associated constants of [`Params`], for when the values are not
+        /// known at compile-time, e.g. when loaded from a configuration file.  The fields have
+        /// the same meanings as the corresponding constants.  The [`Default`] values are the
+        /// same as the default values of the constants.
+        #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+        #[non_exhaustive]
+        pub struct Config
+        {
+            /// Like [`Params::PRECHECK_LIMIT`].  Only used by a separate precheck.
+            pub precheck_limit: u16,
+            /// Like [`Params::FAST_LIMIT_MAX`].
+            pub fast_limit_max: u16,
+            /// Like [`Params::SLOW_LIMIT`].  Zero is treated as one, because otherwise the
+            /// "slow" phase would never switch back and comparing would never finish.
+            pub slow_limit:     u16,
+            /// Amount of nodes that a table can grow to contain initially before reallocating.
+            /// Like the `INITIAL_CAPACITY` of the `Params` of the premade tables.
+            pub table_capacity: usize,
+        }
+
+        impl Config
+        {
+            /// The values of the associated constants of `P`, and the default
+            /// [`table_capacity`](Self::table_capacity).
+            #[inline]
+            #[must_use]
+            pub fn from_params<P: Params>() -> Self
+            {
+                Self {
+                    precheck_limit: P::PRECHECK_LIMIT,
+                    fast_limit_max: P::FAST_LIMIT_MAX,
+                    slow_limit: P::SLOW_LIMIT,
+                    ..Self::default()
+                }
+            }
+        }
+
+        impl Default for Config
+        {
+            #[inline]
+            fn default() -> Self
+            {
+                Self {
+                    precheck_limit: DEFAULT_PRECHECK_LIMIT,
+                    fast_limit_max: default_fast_limit_max(DEFAULT_PRECHECK_LIMIT),
+                    slow_limit:     default_slow_limit(DEFAULT_PRECHECK_LIMIT),
+                    table_capacity: premade::INITIAL_CAPACITY,
+                }
+            }
+        }
+
         /// Specifies use of the "interleave" mode.
         pub struct Interleave<P: Params>
         {
             /// Decremented for every node traversed, and reset when the phase is changed.
-            ticker:        i32,
+            ticker:                   i32,
             /// Table of nodes that have already been seen and recorded as equivalent, for use by
             /// the "slow" phase.
-            equiv_classes: EquivClasses<P::Table>,
+            equiv_classes:            EquivClasses<P::Table>,
             /// State of the (P)RNG that is used to vary the limit of the "fast" phase.
-            rng:           P::RNG,
+            rng:                      P::RNG,
+            /// Like [`Self::FAST_LIMIT_MAX_RANGE_END`], but possibly from a [`Config`].
+            fast_limit_max_range_end: NonZeroU16,
+            /// Like [`Self::SLOW_LIMIT_NEG`], but possibly from a [`Config`].
+            slow_limit_neg:           i32,
         }
 
         impl<P: Params> Interleave<P>
@@ -176,30 +289,93 @@ pub mod modes
             /// Negated [`P::SLOW_LIMIT`](Params::SLOW_LIMIT).
             #[allow(clippy::as_conversions)]
             pub const SLOW_LIMIT_NEG: i32 = -(P::SLOW_LIMIT as i32);
+
+            /// Create a new instance that uses the given `table`.
+            ///
+            /// For use with [`P::Table`](Params::Table) types that cannot implement `Default`,
+            /// e.g. [`slice::Table`](crate::generic::equiv_classes::premade::slice::Table) which
+            /// uses a caller-provided buffer.
+            #[inline]
+            pub fn with_table(table: P::Table) -> Self
+            {
+                Self {
+                    ticker:                   0,
+                    equiv_classes:            EquivClasses::new(table),
+                    rng:                      P::RNG::default(),
+                    fast_limit_max_range_end: Self::FAST_LIMIT_MAX_RANGE_END,
+                    slow_limit_neg:           Self::SLOW_LIMIT_NEG,
+                }
+            }
+
+            /// Use the given `rng`, instead of one created by `Default`, e.g. one created by
+            /// [`SeedableNumberGenerator::from_seed`](random::SeedableNumberGenerator::from_seed)
+            /// for reproducibility or for unpredictability.
+            #[inline]
+            #[must_use]
+            pub fn with_rng(
+                self,
+                rng: P::RNG,
+            ) -> Self
+            {
+                Self { rng, ..self }
+            }
+
+            /// Create a new instance that uses the limits of `config`, instead of the associated
+            /// constants of `P`, and that uses a new table with the capacity of `config`.
+            #[inline]
+            #[must_use]
+            pub fn with_config(config: &Config) -> Self
+            where P::Table: WithCapacity
+            {
+                Self::with_table_and_config(
+                    P::Table::with_capacity(config.table_capacity),
+                    config,
+                )
+            }
+
+            /// Like [`Self::with_config`] but uses the given `table`, like [`Self::with_table`].
+            /// The [`Config::table_capacity`] is ignored.
+            ///
+            /// A [`Config::fast_limit_max`] of `u16::MAX` is treated as one less, and a
+            /// [`Config::slow_limit`] of zero is treated as one.
+            #[inline]
+            pub fn with_table_and_config(
+                table: P::Table,
+                config: &Config,
+            ) -> Self
+            {
+                Self {
+                    fast_limit_max_range_end: match NonZeroU16::new(
+                        config.fast_limit_max.saturating_add(1),
+                    ) {
+                        Some(v) => v,
+                        #[allow(clippy::unreachable)] // The minimum is `1`.
+                        None => unreachable!(),
+                    },
+                    slow_limit_neg: i32::from(config.slow_limit.max(1)).saturating_neg(),
+                    ..Self::with_table(table)
+                }
+            }
         }
 
         impl<P: Params> Default for Interleave<P>
+        where P::Table: Default
         {
             #[inline]
             fn default() -> Self
             {
-                Self {
-                    ticker:        0,
-                    equiv_classes: EquivClasses::default(),
-                    rng:           P::RNG::default(),
-                }
+                Self::with_table(P::Table::default())
             }
         }
 
         /// Enables [`Interleave`] to be used with the algorithm.
-        impl<E, I, T> DescendMode<E> for Interleave<I>
+        impl<N, I, T> Composable<N> for Interleave<I>
         where
-            E: equiv::Params<DescendMode = Self>,
+            N: Node,
             I: Params<Table = T>,
-            T: Table<Node = E::Node>,
-            Infallible: Into<E::Error>,
+            T: UnionFind<Node = N>,
         {
-            type Error = Infallible;
+            type Error = T::Error;
 
             /// Determine whether to use "slow" or "fast" phase, based on our limits.  When "slow"
             /// phase, if the nodes are already known to be equivalent then do not check their
@@ -207,8 +383,8 @@ pub mod modes
             #[inline]
             fn do_edges(
                 &mut self,
-                a: &E::Node,
-                b: &E::Node,
+                a: &N,
+                b: &N,
             ) -> Result<bool, Self::Error>
             {
                 // Only decrement the ticker for branch nodes.
@@ -219,20 +395,20 @@ pub mod modes
                     true
                 }
                 // "slow" limit reached, change to "fast" phase
-                else if self.ticker < Self::SLOW_LIMIT_NEG {
+                else if self.ticker < self.slow_limit_neg {
                     debug_assert_eq!(
                         (self.ticker, false),
-                        Self::SLOW_LIMIT_NEG.overflowing_sub(1),
+                        self.slow_limit_neg.overflowing_sub(1),
                         "overflow is supposed to be impossible here"
                     );
                     // Random limits for "fast" "reduce the likelihood of repeatedly tripping on
                     // worst-case behavior in cases where the sizes of the input graphs happen to
                     // be related to the chosen bounds in a bad way".
-                    self.ticker = self.rng.rand_upto(Self::FAST_LIMIT_MAX_RANGE_END).into();
+                    self.ticker = self.rng.rand_upto(self.fast_limit_max_range_end).into();
                     true
                 }
                 // "slow" phase
-                else if self.equiv_classes.same_class(&a.id(), &b.id()) {
+                else if self.equiv_classes.same_class(&a.id(), &b.id())? {
                     // This is what prevents traversing descendents that have already been
                     // checked, which prevents infinite loops on cycles and is more efficient on
                     // shared structure.
@@ -254,6 +430,15 @@ pub mod modes
             {
                 Ok(true)
             }
+
+            /// Forget all recorded nodes, keeping the table's allocated capacity, and begin again
+            /// with the "fast" phase.  The state of the RNG continues its sequence.
+            #[inline]
+            fn reset(&mut self)
+            {
+                self.ticker = 0;
+                self.equiv_classes.clear();
+            }
         }
     }
 }
@@ -0,0 +1,364 @@
+//! Like the "interleave" mode, but tunes the limits of its phases during an invocation of the
+//! algorithm, based on how often the "slow" phase finds nodes that are already known to be
+//! equivalent.
+//!
+//! The fixed limits of [`Interleave`](super::interleave::Interleave) are a compromise for graphs
+//! of unknown shape.  When the graphs are mostly acyclic without much shared structure, the
+//! lookups of the "slow" phase are mostly wasted, and longer "fast" phases are better.  When the
+//! graphs have much shared structure or cycles, the lookups mostly succeed, and longer "slow"
+//! phases are better because they avoid repeatedly traversing the same structure.  This mode
+//! measures the rate of successful lookups of each "slow" phase, and at its end it adapts the
+//! limits for the following phases accordingly, within bounds.
+//!
+//! The termination guarantees of the "interleave" mode are kept: the "slow" limit never goes
+//! below one node, so every "slow" phase records at least one pair of nodes or finds them already
+//! equivalent, and the "fast" limit is always bounded.  Finding nodes already equivalent still
+//! resets the "slow" phase to continue, which is what prevents excessive depth with
+//! "degenerate cyclic" shapes.
+
+use {
+    super::interleave::{
+        self,
+        random::NumberGenerator as _,
+    },
+    crate::{
+        generic::{
+            equiv::Composable,
+            equiv_classes::{
+                EquivClasses,
+                UnionFind,
+            },
+        },
+        Node,
+    },
+    core::num::NonZeroU16,
+};
+
+
+/// Generic parameters of [`Adaptive`] and its operations.
+///
+/// The limits of [`interleave::Params`] are the initial limits, and the constants here bound how
+/// far they may be adapted.
+pub trait Params: interleave::Params
+{
+    /// Lower bound of adapting [`interleave::Params::FAST_LIMIT_MAX`].
+    const FAST_LIMIT_MAX_MIN: u16 = Self::SLOW_LIMIT;
+    /// Upper bound of adapting [`interleave::Params::FAST_LIMIT_MAX`].
+    const FAST_LIMIT_MAX_MAX: u16 = 8 * Self::FAST_LIMIT_MAX;
+    /// Lower bound of adapting [`interleave::Params::SLOW_LIMIT`].  A value of zero is treated
+    /// as one, so that the "slow" phase is always done.
+    const SLOW_LIMIT_MIN: u16 = Self::SLOW_LIMIT >> 2_u8;
+    /// Upper bound of adapting [`interleave::Params::SLOW_LIMIT`].
+    const SLOW_LIMIT_MAX: u16 = 8 * Self::SLOW_LIMIT;
+}
+
+/// Specifies use of the "adaptive" mode.
+///
+/// At the end of each "slow" phase, when at least half of its lookups found nodes already known
+/// to be equivalent, the maximum of the "fast" limit is halved and the "slow" limit is doubled.
+/// When less than an eighth did, the opposite is done.  Otherwise, the limits are kept.
+pub struct Adaptive<P: Params>
+{
+    /// Decremented for every node traversed, and reset when the phase is changed.
+    ticker:         i32,
+    /// Table of nodes that have already been seen and recorded as equivalent, for use by the
+    /// "slow" phase.
+    equiv_classes:  EquivClasses<P::Table>,
+    /// State of the (P)RNG that is used to vary the limit of the "fast" phase.
+    rng:            P::RNG,
+    /// Current maximum of the randomized limit of the "fast" phase.
+    fast_limit_max: u16,
+    /// Current limit of the "slow" phase.  Never zero.
+    slow_limit:     u16,
+    /// Amount of lookups, in the current "slow" phase, that found nodes already equivalent.
+    hits:           u32,
+    /// Amount of lookups in the current "slow" phase.
+    lookups:        u32,
+}
+
+impl<P: Params> Adaptive<P>
+{
+    /// Create a new instance that uses the given `table`.
+    ///
+    /// Like [`Interleave::with_table`](interleave::Interleave::with_table).
+    #[inline]
+    pub fn with_table(table: P::Table) -> Self
+    {
+        Self {
+            ticker:         0,
+            equiv_classes:  EquivClasses::new(table),
+            rng:            P::RNG::default(),
+            fast_limit_max: P::FAST_LIMIT_MAX,
+            slow_limit:     P::SLOW_LIMIT.max(1),
+            hits:           0,
+            lookups:        0,
+        }
+    }
+
+    /// Use the given `rng`, instead of one created by `Default`.
+    ///
+    /// Like [`Interleave::with_rng`](interleave::Interleave::with_rng).
+    #[inline]
+    #[must_use]
+    pub fn with_rng(
+        self,
+        rng: P::RNG,
+    ) -> Self
+    {
+        Self { rng, ..self }
+    }
+
+    /// The current maximum of the randomized limit of the "fast" phase.
+    #[inline]
+    #[must_use]
+    pub fn fast_limit_max(&self) -> u16
+    {
+        self.fast_limit_max
+    }
+
+    /// The current limit of the "slow" phase.
+    #[inline]
+    #[must_use]
+    pub fn slow_limit(&self) -> u16
+    {
+        self.slow_limit
+    }
+
+    /// Adapt the limits according to the rate of hits of the "slow" phase that just ended, and
+    /// begin counting anew.
+    fn adapt(&mut self)
+    {
+        let (hits, lookups) = (self.hits, self.lookups);
+        self.hits = 0;
+        self.lookups = 0;
+
+        if hits.saturating_mul(2) >= lookups {
+            self.fast_limit_max = (self.fast_limit_max >> 1_u8).max(P::FAST_LIMIT_MAX_MIN);
+            self.slow_limit = self.slow_limit.saturating_mul(2).min(P::SLOW_LIMIT_MAX);
+        }
+        else if hits.saturating_mul(8) < lookups {
+            self.fast_limit_max =
+                self.fast_limit_max.saturating_mul(2).max(1).min(P::FAST_LIMIT_MAX_MAX);
+            self.slow_limit = (self.slow_limit >> 1_u8).max(P::SLOW_LIMIT_MIN);
+        }
+        else {
+            return;
+        }
+        // The bounds might be inconsistent with the initial limits, and zero is not allowed.
+        self.slow_limit = self.slow_limit.max(1);
+    }
+}
+
+impl<P: Params> Default for Adaptive<P>
+where P::Table: Default
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::with_table(P::Table::default())
+    }
+}
+
+/// Enables [`Adaptive`] to be used with the algorithm.
+impl<N, A, T> Composable<N> for Adaptive<A>
+where
+    N: Node,
+    A: Params<Table = T>,
+    T: UnionFind<Node = N>,
+{
+    type Error = T::Error;
+
+    /// Like [`Interleave`](interleave::Interleave), but also counts the lookups of the "slow"
+    /// phase, and adapts the limits when changing to the "fast" phase.
+    #[inline]
+    fn do_edges(
+        &mut self,
+        a: &N,
+        b: &N,
+    ) -> Result<bool, Self::Error>
+    {
+        // Only decrement the ticker for branch nodes.
+        self.ticker = self.ticker.saturating_sub(1);
+
+        // "fast" phase
+        let r = if self.ticker >= 0 {
+            true
+        }
+        // "slow" limit reached, change to "fast" phase
+        else if self.ticker < i32::from(self.slow_limit).saturating_neg() {
+            self.adapt();
+            let range_end = match NonZeroU16::new(self.fast_limit_max.saturating_add(1)) {
+                Some(v) => v,
+                #[allow(clippy::unreachable)] // The minimum is `1`.
+                None => unreachable!(),
+            };
+            self.ticker = self.rng.rand_upto(range_end).into();
+            true
+        }
+        // "slow" phase
+        else {
+            self.lookups = self.lookups.saturating_add(1);
+            if self.equiv_classes.same_class(&a.id(), &b.id())? {
+                self.hits = self.hits.saturating_add(1);
+                // Continue the "slow" phase, like `Interleave`.
+                self.ticker = 0;
+                false
+            }
+            else {
+                true
+            }
+        };
+        Ok(r)
+    }
+
+    /// Always traverse nodes, without limit.
+    #[inline]
+    fn do_traverse(&mut self) -> Result<bool, Self::Error>
+    {
+        Ok(true)
+    }
+
+    /// Forget all recorded nodes, keeping the table's allocated capacity, and begin again with
+    /// the initial limits.  The state of the RNG continues its sequence.
+    #[inline]
+    fn reset(&mut self)
+    {
+        self.ticker = 0;
+        self.equiv_classes.clear();
+        self.fast_limit_max = A::FAST_LIMIT_MAX;
+        self.slow_limit = A::SLOW_LIMIT.max(1);
+        self.hits = 0;
+        self.lookups = 0;
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use {
+        super::*,
+        crate::{
+            basic::recursion::callstack::CallStack,
+            generic::{
+                equiv,
+                equiv_classes::premade::slice,
+            },
+        },
+    };
+
+    struct Unused;
+
+    #[allow(clippy::unreachable)]
+    impl Node for Unused
+    {
+        type Cmp = bool;
+        type Id = u8;
+        type Index = u8;
+
+        fn id(&self) -> Self::Id
+        {
+            unreachable!()
+        }
+
+        fn get_edge(
+            &self,
+            _index: &Self::Index,
+        ) -> Option<Self>
+        {
+            unreachable!()
+        }
+
+        fn equiv_modulo_edges(
+            &self,
+            _other: &Self,
+        ) -> bool
+        {
+            unreachable!()
+        }
+    }
+
+    #[derive(Default)]
+    struct Zero;
+
+    impl interleave::random::NumberGenerator for Zero
+    {
+        fn rand_upto(
+            &mut self,
+            _exclusive_end: NonZeroU16,
+        ) -> u16
+        {
+            0
+        }
+    }
+
+    struct Args;
+
+    impl equiv::Params for Args
+    {
+        type DescendMode = Adaptive<Self>;
+        type Error = slice::TableFull;
+        type Node = Unused;
+        type RecurMode = CallStack;
+    }
+
+    impl interleave::Params for Args
+    {
+        type Node = Unused;
+        type RNG = Zero;
+        type Table = slice::Table<'static, Self>;
+    }
+
+    impl Params for Args
+    {
+        const SLOW_LIMIT_MIN: u16 = 0;
+    }
+
+    impl slice::Params for Args
+    {
+        type BuildHasher = slice::BuildFnvHasher;
+        type Node = Unused;
+    }
+
+    fn phase(
+        it: &mut Adaptive<Args>,
+        hits: u32,
+        lookups: u32,
+    )
+    {
+        it.hits = hits;
+        it.lookups = lookups;
+        it.adapt();
+        assert_eq!((it.hits, it.lookups), (0, 0));
+    }
+
+    #[test]
+    fn adapt()
+    {
+        let mut it = Adaptive::<Args>::with_table(slice::Table::new(&mut []));
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (800, 40));
+
+        // Few hits favor the "fast" phase.
+        phase(&mut it, 0, 41);
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (1600, 20));
+        for _ in 0 .. 10 {
+            phase(&mut it, 0, 41);
+        }
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (6400, 1));
+
+        // Neither few nor many hits keep the limits.
+        phase(&mut it, 2, 10);
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (6400, 1));
+
+        // Many hits favor the "slow" phase.
+        phase(&mut it, 5, 10);
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (3200, 2));
+        for _ in 0 .. 20 {
+            phase(&mut it, 100, 101);
+        }
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (40, 320));
+
+        Composable::<Unused>::reset(&mut it);
+        assert_eq!((it.fast_limit_max(), it.slow_limit()), (800, 40));
+    }
+}
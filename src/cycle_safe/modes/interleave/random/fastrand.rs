@@ -1,6 +1,9 @@
 //! Use [fastrand](https://crates.io/crates/fastrand) as the choice of PRNG.
 
-use core::num::NonZeroU16;
+use {
+    core::num::NonZeroU16,
+    fastrand::Rng,
+};
 
 /// Use the thread-local-state ability of [`fastrand`], represented as this zero-sized unit struct
 /// so that our traits can be `impl`ed on it.
@@ -19,3 +22,32 @@ impl super::NumberGenerator for RandomNumberGenerator
         fastrand::u16(0 .. exclusive_end.get())
     }
 }
+
+/// Wrap an own [`Rng`], instead of the thread-local state, so that it can be seeded.
+///
+/// The [`Default`] instances are seeded from the thread-local state of [`fastrand`], which is
+/// seeded randomly, and so each differs.
+#[derive(Default)]
+#[non_exhaustive]
+pub struct SeededRandomNumberGenerator(Rng);
+
+impl super::NumberGenerator for SeededRandomNumberGenerator
+{
+    #[inline]
+    fn rand_upto(
+        &mut self,
+        exclusive_end: NonZeroU16,
+    ) -> u16
+    {
+        self.0.u16(0 .. exclusive_end.get())
+    }
+}
+
+impl super::SeedableNumberGenerator for SeededRandomNumberGenerator
+{
+    #[inline]
+    fn from_seed(seed: u64) -> Self
+    {
+        Self(Rng::with_seed(seed))
+    }
+}
@@ -1,5 +1,7 @@
 //! Package features select which (pseudo)random-number generator(s) is/are available, and the
-//! highest-priority one is the default used with the premade items.
+//! highest-priority one is the default used with the premade items.  The built-in
+//! [`splitmix`](crate::cycle_safe::modes::interleave::random::splitmix) is always available, and
+//! is the default when no other is enabled.
 //!
 //! It was not decided which would always be the best for all users, so instead support a variety.
 //! If you add one that was not already here, consider whether it can be no-std, if you want to
@@ -21,6 +23,9 @@ cfg_if! {
     else if #[cfg(feature = "fastrand")] {
         pub use self::fastrand as default;
     }
+    else {
+        pub use self::splitmix as default;
+    }
 }
 
 
@@ -30,6 +35,8 @@ pub mod fastrand;
 #[cfg(feature = "oorandom")]
 pub mod oorandom;
 
+pub mod splitmix;
+
 #[cfg(feature = "wyrng")]
 pub mod wyrng;
 
@@ -47,3 +54,18 @@ pub trait NumberGenerator: Default
         exclusive_end: NonZeroU16,
     ) -> u16;
 }
+
+/// A [`NumberGenerator`] that can be created from a caller-chosen seed.
+///
+/// The same seed always gives the same sequence, which enables reproducing the traversal of an
+/// invocation of the algorithm, e.g. for debugging.  Different seeds per invocation, e.g. chosen
+/// randomly by the caller, prevent inputs from being tuned adversarially against a known
+/// constant seed.
+///
+/// Each PRNG module has a `SeededRandomNumberGenerator` type that `impl`s this, which is the same
+/// as its `RandomNumberGenerator` type when that can be seeded.
+pub trait SeedableNumberGenerator: NumberGenerator
+{
+    /// Create a new instance whose sequence is determined by `seed`.
+    fn from_seed(seed: u64) -> Self;
+}
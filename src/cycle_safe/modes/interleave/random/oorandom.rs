@@ -39,3 +39,15 @@ impl super::NumberGenerator for RandomNumberGenerator
         self.0.rand_range(0 .. exclusive_end) as u16
     }
 }
+
+impl super::SeedableNumberGenerator for RandomNumberGenerator
+{
+    #[inline]
+    fn from_seed(seed: u64) -> Self
+    {
+        Self(Rand32::new(seed))
+    }
+}
+
+/// The [`RandomNumberGenerator`] is already seedable.
+pub type SeededRandomNumberGenerator = RandomNumberGenerator;
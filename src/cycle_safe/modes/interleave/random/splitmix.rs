@@ -0,0 +1,53 @@
+//! Use a built-in [SplitMix64](https://prng.di.unimi.it/splitmix64.c) as the choice of PRNG.
+//!
+//! Always available, without any dependency nor the "std" or "alloc" features, and so it is the
+//! default when no other PRNG is enabled.  Its quality is plenty for our use of varying the limit
+//! of the "fast" phase.
+
+use core::num::NonZeroU16;
+
+/// The state of a `SplitMix64` generator.
+///
+/// The [`Default`] instances all begin with the same constant state.
+#[derive(Default)]
+pub struct RandomNumberGenerator(u64);
+
+impl RandomNumberGenerator
+{
+    /// Advance the state and return the next number.
+    fn next(&mut self) -> u64
+    {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30_u8)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27_u8)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31_u8)
+    }
+}
+
+impl super::NumberGenerator for RandomNumberGenerator
+{
+    #[inline]
+    fn rand_upto(
+        &mut self,
+        exclusive_end: NonZeroU16,
+    ) -> u16
+    {
+        #![allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+        // This `as` conversion cast is ok because we want truncation.
+        self.next() as u16 % exclusive_end
+    }
+}
+
+impl super::SeedableNumberGenerator for RandomNumberGenerator
+{
+    /// The `seed` is the initial state.
+    #[inline]
+    fn from_seed(seed: u64) -> Self
+    {
+        Self(seed)
+    }
+}
+
+/// The [`RandomNumberGenerator`] is already seedable.
+pub type SeededRandomNumberGenerator = RandomNumberGenerator;
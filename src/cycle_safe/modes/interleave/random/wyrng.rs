@@ -22,3 +22,16 @@ impl super::NumberGenerator for RandomNumberGenerator
         wyrng(&mut self.0) as u16 % exclusive_end
     }
 }
+
+impl super::SeedableNumberGenerator for RandomNumberGenerator
+{
+    /// The `seed` is the initial state.
+    #[inline]
+    fn from_seed(seed: u64) -> Self
+    {
+        Self(seed)
+    }
+}
+
+/// The [`RandomNumberGenerator`] is already seedable.
+pub type SeededRandomNumberGenerator = RandomNumberGenerator;
@@ -152,7 +152,19 @@ pub mod recursion
             #[inline]
             fn default() -> Self
             {
-                Self(LazyVecQueue::with_capacity(P::INITIAL_CAPACITY))
+                Self::with_capacity(P::INITIAL_CAPACITY)
+            }
+        }
+
+        impl<P: Params> RecurQueue<P>
+        {
+            /// Create a new instance with the given `capacity`, instead of
+            /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY), e.g. chosen at run-time.
+            #[inline]
+            #[must_use]
+            pub fn with_capacity(capacity: usize) -> Self
+            {
+                Self(LazyVecQueue::with_capacity(capacity))
             }
         }
 
@@ -200,6 +212,12 @@ pub mod recursion
                 self.0.clear();
                 self
             }
+
+            #[inline]
+            fn clear(&mut self)
+            {
+                self.0.clear();
+            }
         }
     }
 }
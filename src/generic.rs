@@ -1,5 +1,6 @@
 pub use premade::*;
 
+pub mod combinators;
 pub mod equiv_classes;
 
 
@@ -113,8 +114,18 @@ mod premade
                 RecurMode,
             },
             crate::{
+                anticipated_or_like::Infallible,
                 basic::modes::limited::Limited,
-                cycle_safe::modes::interleave,
+                cycle_safe::modes::interleave::{
+                    self,
+                    random::SeedableNumberGenerator,
+                    Config,
+                    Interleave,
+                },
+                generic::equiv_classes::{
+                    UnionFind,
+                    WithCapacity,
+                },
                 Node,
             },
             sealed::{
@@ -133,12 +144,28 @@ mod premade
             /// Type of recursion mode for the interleave.
             type InterleaveRecurMode: RecurMode<InterleaveArgs<N, Self>>;
             /// Type that `impl`s the arguments for the generic parameters for the interleave.
-            type InterleaveParams: interleave::Params<Node = N>;
+            type InterleaveParams: InfallibleTableParams<Node = N>;
             /// Type that represents the errors that can occur from [`Self::PrecheckRecurMode`]
             /// and [`Self::InterleaveRecurMode`].
             type Error;
         }
 
+        /// [`interleave::Params`] whose [`Table`](interleave::Params::Table) is infallible and
+        /// creatable by `Default`, like the premade tables of shared-ownership classes, which
+        /// the interleave of [`equiv`] requires.  Implemented for all such.
+        pub trait InfallibleTableParams:
+            interleave::Params<Table = <Self as InfallibleTableParams>::InfallibleTable>
+        {
+            /// Same as the [`Table`](interleave::Params::Table).
+            type InfallibleTable: UnionFind<Node = Self::Node, Error = Infallible> + Default;
+        }
+
+        impl<I: interleave::Params> InfallibleTableParams for I
+        where I::Table: UnionFind<Error = Infallible> + Default
+        {
+            type InfallibleTable = I::Table;
+        }
+
         /// Equivalence predicate that can handle cyclic graphs, but first tries the precheck that
         /// is faster for small acyclic graphs, and that requires choosing specific type arguments
         /// that determine the implementations of internal dynamic data structures.  Safe for
@@ -159,14 +186,88 @@ mod premade
         {
             use interleave::Params as _;
 
-            let mut e =
-                Equiv::<PrecheckArgs<N, P>>::new(Limited(P::InterleaveParams::PRECHECK_LIMIT));
+            precheck_then_interleave::<N, P, _>(
+                P::InterleaveParams::PRECHECK_LIMIT,
+                Interleave::default,
+                a,
+                b,
+            )
+        }
+
+        /// Like [`equiv`] but uses the limits and table capacity of `config`, chosen at
+        /// run-time, instead of the associated constants of the
+        /// [`P::InterleaveParams`](Params::InterleaveParams) and of its table's parameters.
+        ///
+        /// # Errors
+        /// Same as [`equiv`].
+        #[inline]
+        pub fn equiv_with<N, P>(
+            config: &Config,
+            a: N,
+            b: N,
+        ) -> Result<N::Cmp, P::Error>
+        where
+            N: Node + Clone,
+            P: Params<N>,
+            <P::InterleaveParams as interleave::Params>::Table: WithCapacity,
+        {
+            precheck_then_interleave::<N, P, _>(
+                config.precheck_limit,
+                || Interleave::with_config(config),
+                a,
+                b,
+            )
+        }
+
+        /// Like [`equiv`] but the interleave uses an RNG created from `seed`, instead of by
+        /// `Default`.  (The precheck does not use an RNG.)
+        ///
+        /// # Errors
+        /// Same as [`equiv`].
+        #[inline]
+        pub fn equiv_seeded<N, P>(
+            seed: u64,
+            a: N,
+            b: N,
+        ) -> Result<N::Cmp, P::Error>
+        where
+            N: Node + Clone,
+            P: Params<N>,
+            <P::InterleaveParams as interleave::Params>::RNG: SeedableNumberGenerator,
+        {
+            use interleave::Params as _;
+
+            precheck_then_interleave::<N, P, _>(
+                P::InterleaveParams::PRECHECK_LIMIT,
+                || Interleave::default().with_rng(SeedableNumberGenerator::from_seed(seed)),
+                a,
+                b,
+            )
+        }
+
+        /// Do the precheck with the given limit and, if that is reached, do the interleave with
+        /// the mode given by `interleave`, reusing the recursion-mode value.
+        fn precheck_then_interleave<N, P, F>(
+            precheck_limit: u16,
+            interleave: F,
+            a: N,
+            b: N,
+        ) -> Result<N::Cmp, P::Error>
+        where
+            N: Node + Clone,
+            P: Params<N>,
+            F: FnOnce() -> Interleave<P::InterleaveParams>,
+        {
+            let mut e = Equiv::<PrecheckArgs<N, P>>::new(Limited(precheck_limit));
 
             match e.equiv(a.clone(), b.clone()) {
                 Ok(cmp) => Ok(cmp),
                 Err(PrecheckError::RecurError(e)) => Err(e),
                 Err(PrecheckError::LimitReached) => {
-                    let mut e: Equiv<InterleaveArgs<N, P>> = e.into();
+                    let mut e = Equiv::<InterleaveArgs<N, P>>::with_modes(
+                        interleave(),
+                        e.recur_mode.reset().into(),
+                    );
                     e.equiv(a, b).map_err(|InterleaveError(error)| error)
                 },
             }
@@ -177,7 +278,10 @@ mod premade
 
 mod modes
 {
-    use super::equiv::Params;
+    use {
+        super::equiv::Params,
+        crate::Node,
+    };
 
     /// Controls if node edges are descended into.
     pub trait DescendMode<P: Params>
@@ -217,6 +321,79 @@ mod modes
         /// Returning `Err` causes the invocation of the algorithm to abort early and immediately
         /// return the converted error.
         fn do_traverse(&mut self) -> Result<bool, Self::Error>;
+
+        /// Reset to a fresh state, for reuse in an independent invocation of the algorithm,
+        /// while preserving capacity, if relevant.
+        ///
+        /// The default implementation does nothing, which is appropriate for types that have no
+        /// state that is specific to an invocation.
+        #[inline]
+        fn reset(&mut self) {}
+    }
+
+    /// Like [`DescendMode`] but depends only on the type of node, not on the other
+    /// [`Params`](super::equiv::Params), which enables composing modes with the
+    /// [combinators](super::combinators).
+    ///
+    /// Every type that implements this also implements [`DescendMode`], for any `P:
+    /// equiv::Params` with the same node type and an error type that this error type can be
+    /// converted into.
+    pub trait Composable<N: Node>
+    {
+        /// Type of error that can occur.
+        type Error;
+
+        /// Like [`DescendMode::do_edges`].
+        ///
+        /// # Errors
+        /// Like [`DescendMode::do_edges`].
+        fn do_edges(
+            &mut self,
+            a: &N,
+            b: &N,
+        ) -> Result<bool, Self::Error>;
+
+        /// Like [`DescendMode::do_traverse`].
+        ///
+        /// # Errors
+        /// Like [`DescendMode::do_traverse`].
+        fn do_traverse(&mut self) -> Result<bool, Self::Error>;
+
+        /// Like [`DescendMode::reset`].
+        #[inline]
+        fn reset(&mut self) {}
+    }
+
+    /// Enables any [`Composable`] to be used with the algorithm.
+    impl<P, M> DescendMode<P> for M
+    where
+        P: Params,
+        M: Composable<P::Node>,
+        M::Error: Into<P::Error>,
+    {
+        type Error = M::Error;
+
+        #[inline]
+        fn do_edges(
+            &mut self,
+            a: &P::Node,
+            b: &P::Node,
+        ) -> Result<bool, Self::Error>
+        {
+            Composable::do_edges(self, a, b)
+        }
+
+        #[inline]
+        fn do_traverse(&mut self) -> Result<bool, Self::Error>
+        {
+            Composable::do_traverse(self)
+        }
+
+        #[inline]
+        fn reset(&mut self)
+        {
+            Composable::reset(self);
+        }
     }
 }
 
@@ -230,6 +407,7 @@ mod recursion
             Params,
         },
         crate::Node,
+        core::mem,
     };
 
     /// [`Node`]s at the same position in the input graphs to compare.
@@ -285,10 +463,24 @@ mod recursion
         /// newly-created value may be returned if desired.
         #[must_use]
         fn reset(self) -> Self;
+
+        /// Like [`Self::reset`] but in place, e.g. for reusing an [`Equiv`] across independent
+        /// invocations.
+        ///
+        /// The default implementation replaces `self` with [`Self::reset`] of it, which creates a
+        /// [`Default`] value temporarily.  Types whose `Default` allocates should override this
+        /// to clear themselves in place.
+        #[inline]
+        fn clear(&mut self)
+        {
+            *self = mem::take(self).reset();
+        }
     }
 }
 
 
+pub(crate) use edges_iter::increment_index;
+
 mod edges_iter
 {
     use {
@@ -380,7 +572,7 @@ mod edges_iter
         }
     }
 
-    fn increment_index<T: Step>(i: &T) -> Option<T>
+    pub(crate) fn increment_index<T: Step>(i: &T) -> Option<T>
     {
         cfg_if! {
             if #[cfg(feature = "anticipate")] {
@@ -399,7 +591,10 @@ pub mod equiv
 {
     pub use super::{
         edges_iter::EdgesIter,
-        modes::DescendMode,
+        modes::{
+            Composable,
+            DescendMode,
+        },
         recursion::{
             Counterparts,
             CounterpartsResult,
@@ -447,6 +642,36 @@ pub mod equiv
         {
             Self { descend_mode, recur_mode: P::RecurMode::default() }
         }
+
+        /// Like [`Self::new`] but uses the given `recur_mode`, e.g. one created with a capacity
+        /// chosen at run-time.
+        #[inline]
+        pub fn with_modes(
+            descend_mode: P::DescendMode,
+            recur_mode: P::RecurMode,
+        ) -> Self
+        {
+            Self { descend_mode, recur_mode }
+        }
+
+        /// Reset to a fresh state, so that `self` can be reused for an independent invocation of
+        /// the algorithm, while preserving the allocated capacity of its dynamic data structures.
+        ///
+        /// This is more efficient than creating a new instance for each of many comparisons,
+        /// because the table of equivalence classes of the
+        /// [`Interleave`](crate::cycle_safe::modes::interleave::Interleave) mode and the storage
+        /// of the [`RecurMode`] are not freed and reallocated.  Unlike with
+        /// [`Self::equiv_many`], no knowledge is retained from previous comparisons.
+        ///
+        /// Note that the remaining limit of a [`DescendMode`] like
+        /// [`Limited`](crate::basic::modes::limited::Limited) is not restored, because the
+        /// original limit is not known to it.
+        #[inline]
+        pub fn reset(&mut self)
+        {
+            self.descend_mode.reset();
+            self.recur_mode.clear();
+        }
     }
 
     impl<P: Params> Default for Equiv<P>
@@ -539,6 +764,41 @@ pub mod equiv
             }
         }
 
+        /// Like [`Self::equiv`] but compares multiple pairs of roots, e.g. of forests of graphs.
+        ///
+        /// All the pairs are compared using this same `self`, and so the state of the
+        /// [`DescendMode`] (e.g. the table of equivalence classes of the
+        /// [`Interleave`](crate::cycle_safe::modes::interleave::Interleave) mode) and the storage
+        /// of the [`RecurMode`] are shared across the pairs.  This retains the knowledge of
+        /// structure, that is shared between the graphs of different pairs, that was already
+        /// found to be equivalent, which avoids repeating the work of traversing such structure
+        /// again.  (Similarly, a limit of a [`DescendMode`] like
+        /// [`Limited`](crate::basic::modes::limited::Limited) applies to the total across the
+        /// pairs.)
+        ///
+        /// Returns the result of the first pair that compares as inequivalent, without comparing
+        /// any further pairs, or returns the value that represents equivalence if all the pairs
+        /// are equivalent (including when there are no pairs).
+        ///
+        /// # Errors
+        /// Same as [`Self::equiv`].  Any further pairs are not compared.
+        #[inline]
+        pub fn equiv_many<I>(
+            &mut self,
+            pairs: I,
+        ) -> Result<<P::Node as Node>::Cmp, P::Error>
+        where
+            I: IntoIterator<Item = (P::Node, P::Node)>,
+        {
+            for (a, b) in pairs {
+                let cmp = self.equiv(a, b)?;
+                if !cmp.is_equiv() {
+                    return Ok(cmp);
+                }
+            }
+            Ok(Cmp::new_equiv())
+        }
+
         /// The main logic of the algorithm.
         ///
         /// Must not be used as the initial entry-point, but may be called by
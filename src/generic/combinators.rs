@@ -0,0 +1,206 @@
+//! Combinators of [`Composable`] modes, for using multiple modes at once without implementing a
+//! new [`DescendMode`](super::equiv::DescendMode) for each combination.
+//!
+//! E.g. `Both<Limited<u32>, Interleave<P>>` is like the "interleave" mode but also limits the
+//! amount of nodes traversed.
+//!
+//! The combinators always consult both of their component modes, without short-circuiting, so
+//! that stateful modes (like those that count nodes) observe every node.  When both components
+//! give an error, the error of the first one is returned.
+
+use {
+    super::equiv::Composable,
+    crate::{
+        anticipated_or_like::Infallible,
+        Node,
+    },
+};
+
+
+/// Specifies descending only when both modes do.
+///
+/// Errors are propagated as the sum type [`Error`].
+#[derive(Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Both<A, B>(pub A, pub B);
+
+/// Specifies descending when either mode does.
+///
+/// Errors are propagated as the sum type [`Error`].
+#[derive(Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Either<A, B>(pub A, pub B);
+
+/// Specifies converting the errors of a mode with a function.
+///
+/// Useful for converting the sum type [`Error`] into a single error type.
+pub struct Map<M, F>
+{
+    /// The mode whose errors are converted.
+    mode: M,
+    /// Converts the errors.
+    f:    F,
+}
+
+/// [`Err`] type of [`Both`] and [`Either`], which is the sum of the error types of their
+/// component modes.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum Error<A, B>
+{
+    /// The first mode errored.
+    First(A),
+    /// The second mode errored.
+    Second(B),
+}
+
+/// Enables `P: equiv::Params<Error = Error<A, B>, RecurMode = R>`
+/// where `R: RecurMode<Error = Infallible>`.
+impl<A, B> From<Infallible> for Error<A, B>
+{
+    #[inline]
+    fn from(_: Infallible) -> Self
+    {
+        #![allow(clippy::unreachable)] // Truly unreachable.
+        unreachable!()
+    }
+}
+
+/// Combine the results of the component modes, giving the error of the first one that errored.
+fn combine<A, B>(
+    a: Result<bool, A>,
+    b: Result<bool, B>,
+    op: fn(bool, bool) -> bool,
+) -> Result<bool, Error<A, B>>
+{
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok(op(a, b)),
+        (Err(e), _) => Err(Error::First(e)),
+        (_, Err(e)) => Err(Error::Second(e)),
+    }
+}
+
+impl<N, A, B> Composable<N> for Both<A, B>
+where
+    N: Node,
+    A: Composable<N>,
+    B: Composable<N>,
+{
+    type Error = Error<A::Error, B::Error>;
+
+    /// Start handling node edges only when both modes do.
+    #[inline]
+    fn do_edges(
+        &mut self,
+        a: &N,
+        b: &N,
+    ) -> Result<bool, Self::Error>
+    {
+        combine(self.0.do_edges(a, b), self.1.do_edges(a, b), |x, y| x && y)
+    }
+
+    /// Traverse nodes only when both modes do.
+    #[inline]
+    fn do_traverse(&mut self) -> Result<bool, Self::Error>
+    {
+        combine(self.0.do_traverse(), self.1.do_traverse(), |x, y| x && y)
+    }
+
+    /// Reset both modes.
+    #[inline]
+    fn reset(&mut self)
+    {
+        self.0.reset();
+        self.1.reset();
+    }
+}
+
+impl<N, A, B> Composable<N> for Either<A, B>
+where
+    N: Node,
+    A: Composable<N>,
+    B: Composable<N>,
+{
+    type Error = Error<A::Error, B::Error>;
+
+    /// Start handling node edges when either mode does.
+    #[inline]
+    fn do_edges(
+        &mut self,
+        a: &N,
+        b: &N,
+    ) -> Result<bool, Self::Error>
+    {
+        combine(self.0.do_edges(a, b), self.1.do_edges(a, b), |x, y| x || y)
+    }
+
+    /// Traverse nodes when either mode does.
+    #[inline]
+    fn do_traverse(&mut self) -> Result<bool, Self::Error>
+    {
+        combine(self.0.do_traverse(), self.1.do_traverse(), |x, y| x || y)
+    }
+
+    /// Reset both modes.
+    #[inline]
+    fn reset(&mut self)
+    {
+        self.0.reset();
+        self.1.reset();
+    }
+}
+
+impl<M, F> Map<M, F>
+{
+    /// Create a new instance that converts the errors of `mode` with `f`.
+    #[inline]
+    pub fn new(
+        mode: M,
+        f: F,
+    ) -> Self
+    {
+        Self { mode, f }
+    }
+
+    /// The mode whose errors are converted.
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> &M
+    {
+        &self.mode
+    }
+}
+
+impl<N, M, F, E> Composable<N> for Map<M, F>
+where
+    N: Node,
+    M: Composable<N>,
+    F: FnMut(M::Error) -> E,
+{
+    type Error = E;
+
+    /// Like the inner mode, with its error converted.
+    #[inline]
+    fn do_edges(
+        &mut self,
+        a: &N,
+        b: &N,
+    ) -> Result<bool, Self::Error>
+    {
+        self.mode.do_edges(a, b).map_err(&mut self.f)
+    }
+
+    /// Like the inner mode, with its error converted.
+    #[inline]
+    fn do_traverse(&mut self) -> Result<bool, Self::Error>
+    {
+        self.mode.do_traverse().map_err(&mut self.f)
+    }
+
+    /// Reset the inner mode.
+    #[inline]
+    fn reset(&mut self)
+    {
+        self.mode.reset();
+    }
+}
@@ -14,7 +14,10 @@
 //! (unlike [`RefCell`](core::cell::RefCell)).
 
 use {
-    crate::Node,
+    crate::{
+        anticipated_or_like::Infallible,
+        Node,
+    },
     core::{
         cell::Cell,
         ops::Deref,
@@ -185,88 +188,204 @@ pub trait Table: Default
         k: <Self::Node as Node>::Id,
         v: Self::Rc,
     );
-}
 
-/// The classes of the nodes that are known to be equivalent, for an invocation of the algorithm.
-#[derive(Default)]
-pub(crate) struct EquivClasses<T>
-{
-    /// Table that associates nodes by ID with their equivalence class.
-    table: T,
+    /// Remove all associations, so that the table can be reused for an independent invocation of
+    /// the algorithm.
+    ///
+    /// The default implementation replaces `self` with a new `Default` value.  Implementations
+    /// should override this to keep their allocated capacity, when possible.
+    #[inline]
+    fn clear(&mut self)
+    {
+        *self = Self::default();
+    }
 }
 
-impl<T: Table> EquivClasses<T>
+/// Allows being generic over the representation of the equivalence classes, i.e. over the
+/// union-find structure that records nodes as equivalent.
+///
+/// Every [`Table`] is a `UnionFind` that uses [`Class`] objects with shared ownership.  Other
+/// representations, e.g. `premade::arena::Table` (with the `std` feature) which stores the
+/// classes contiguously or [`premade::slice::Table`] which does not allocate, can be provided by
+/// `impl`ing this directly.
+pub trait UnionFind
 {
-    /// First time both nodes are seen.
+    /// The node type that a `Self` handles.
+    type Node: Node;
+    /// Type that represents the errors that can occur from recording nodes, e.g. when a table
+    /// with fixed capacity is full.
+    type Error;
+
+    /// Check if the given node IDs are already known to be equivalent.  If not, they will be made
+    /// members of the same equivalence class, merging classes if needed.
     ///
-    /// Immediately record them as being in the same equivalence class, before checking their
-    /// descendents, by associating their IDs with a new equivalence class.
-    fn none_seen(
+    /// Returns `true` if they were already recorded as equivalent, and their descendents do not
+    /// need to be checked.  Returns `false` if not already recorded as equivalent, and their
+    /// descendents do need to be checked, and they will be recorded as equivalent for if they are
+    /// seen again.
+    ///
+    /// # Errors
+    /// If the nodes cannot be recorded, return an `Err` with a [`Self::Error`] that represents
+    /// the error.
+    fn same_class(
         &mut self,
-        ak: &<T::Node as Node>::Id,
-        bk: &<T::Node as Node>::Id,
-    )
+        ak: &<Self::Node as Node>::Id,
+        bk: &<Self::Node as Node>::Id,
+    ) -> Result<bool, Self::Error>;
+
+    /// Forget all recorded nodes, so that `self` can be reused for an independent invocation of
+    /// the algorithm.  Should keep any allocated capacity.
+    fn clear(&mut self);
+}
+
+/// Tables that can be created with a chosen initial capacity, e.g. from a run-time
+/// [`Config`](crate::cycle_safe::modes::interleave::Config).
+pub trait WithCapacity
+{
+    /// Create a new instance that can contain `capacity` nodes before reallocating.  Types that
+    /// do not have a notion of capacity may ignore it.
+    fn with_capacity(capacity: usize) -> Self;
+}
+
+/// Tables that can tell how many node IDs they associate, e.g. to see how much of the capacity
+/// of a reused table was needed.
+pub trait Len: Table
+{
+    /// The amount of node IDs that are associated.
+    fn len(&self) -> usize;
+
+    /// Whether no node IDs are associated.
+    #[inline]
+    fn is_empty(&self) -> bool
     {
-        let ac = Class::new();
-        let bc = T::Rc::clone(&ac);
-        self.table.insert(ak.clone(), ac);
-        self.table.insert(bk.clone(), bc);
+        self.len() == 0
     }
+}
 
-    /// First time one node is seen but the other has already been seen.
-    ///
-    /// Immediately record them as being in the same equivalence class, before checking their
-    /// descendents, by associating the ID of the unseen with the equivalence class of the seen.
-    ///
-    /// This also causes any further nodes that were already members of the class to now be
-    /// transitively equivalent to the unseen, which can improve efficiency for some shapes.
-    fn some_seen(
+impl<T: Table> UnionFind for T
+{
+    type Error = Infallible;
+    type Node = T::Node;
+
+    #[inline]
+    fn same_class(
         &mut self,
-        oc: &T::Rc,
-        k: &<T::Node as Node>::Id,
-    )
+        ak: &<Self::Node as Node>::Id,
+        bk: &<Self::Node as Node>::Id,
+    ) -> Result<bool, Self::Error>
     {
-        let r = Class::get_rep(oc);
-        self.table.insert(k.clone(), r);
+        Ok(match (self.get(ak), self.get(bk)) {
+            (None, None) => {
+                none_seen(self, ak, bk);
+                false
+            },
+            (Some(ac), None) => {
+                let ac = &T::Rc::clone(ac); // To end borrow of `self`.
+                some_seen(self, ac, bk);
+                false
+            },
+            (None, Some(bc)) => {
+                let bc = &T::Rc::clone(bc); // To end borrow of `self`.
+                some_seen(self, bc, ak);
+                false
+            },
+            (Some(ac), Some(bc)) => all_seen::<T>(ac, bc),
+        })
     }
 
-    /// Both nodes have already been seen, but maybe not already known to be equivalent.
-    ///
-    /// Return `true` if already recorded as being in the same equivalence class.
-    ///
-    /// Else return `false`, and immediately record them as being in the same equivalence class,
-    /// before checking their descendents, by merging their classes into a union.  This also
-    /// causes any further nodes that were already members of the classes to now be transitively
-    /// equivalent to each other, which can improve efficiency for some shapes.
-    fn all_seen(
-        ac: &T::Rc,
-        bc: &T::Rc,
-    ) -> bool
+    #[inline]
+    fn clear(&mut self)
     {
-        let (ar, aw) = Class::get_rep_and_weight(ac);
-        let (br, bw) = Class::get_rep_and_weight(bc);
+        Table::clear(self);
+    }
+}
+
+/// First time both nodes are seen.
+///
+/// Immediately record them as being in the same equivalence class, before checking their
+/// descendents, by associating their IDs with a new equivalence class.
+fn none_seen<T: Table>(
+    table: &mut T,
+    ak: &<T::Node as Node>::Id,
+    bk: &<T::Node as Node>::Id,
+)
+{
+    let ac = Class::new();
+    let bc = T::Rc::clone(&ac);
+    table.insert(ak.clone(), ac);
+    table.insert(bk.clone(), bc);
+}
+
+/// First time one node is seen but the other has already been seen.
+///
+/// Immediately record them as being in the same equivalence class, before checking their
+/// descendents, by associating the ID of the unseen with the equivalence class of the seen.
+///
+/// This also causes any further nodes that were already members of the class to now be
+/// transitively equivalent to the unseen, which can improve efficiency for some shapes.
+fn some_seen<T: Table>(
+    table: &mut T,
+    oc: &T::Rc,
+    k: &<T::Node as Node>::Id,
+)
+{
+    let r = Class::get_rep(oc);
+    table.insert(k.clone(), r);
+}
+
+/// Both nodes have already been seen, but maybe not already known to be equivalent.
+///
+/// Return `true` if already recorded as being in the same equivalence class.
+///
+/// Else return `false`, and immediately record them as being in the same equivalence class,
+/// before checking their descendents, by merging their classes into a union.  This also causes
+/// any further nodes that were already members of the classes to now be transitively equivalent
+/// to each other, which can improve efficiency for some shapes.
+fn all_seen<T: Table>(
+    ac: &T::Rc,
+    bc: &T::Rc,
+) -> bool
+{
+    let (ar, aw) = Class::get_rep_and_weight(ac);
+    let (br, bw) = Class::get_rep_and_weight(bc);
+
+    // Already same class.
+    if Class::eq_rep(&ar, &br) {
+        true
+    }
+    // Merge classes, according to the "weighted union rule" as prescribed by the paper.
+    else {
+        let (larger_rep, smaller_rep);
 
-        // Already same class.
-        if Class::eq_rep(&ar, &br) {
-            true
+        if aw >= bw {
+            larger_rep = ar;
+            smaller_rep = br;
         }
-        // Merge classes, according to the "weighted union rule" as prescribed by the paper.
         else {
-            let (larger_rep, smaller_rep);
+            larger_rep = br;
+            smaller_rep = ar;
+        }
+        Class::set_rep(&larger_rep, aw.saturating_add(bw));
+        Class::set_link(&smaller_rep, larger_rep);
 
-            if aw >= bw {
-                larger_rep = ar;
-                smaller_rep = br;
-            }
-            else {
-                larger_rep = br;
-                smaller_rep = ar;
-            }
-            Class::set_rep(&larger_rep, aw.saturating_add(bw));
-            Class::set_link(&smaller_rep, larger_rep);
+        false
+    }
+}
 
-            false
-        }
+/// The classes of the nodes that are known to be equivalent, for an invocation of the algorithm.
+#[derive(Default)]
+pub(crate) struct EquivClasses<T>
+{
+    /// Union-find structure that associates nodes by ID with their equivalence class.
+    table: T,
+}
+
+impl<T: UnionFind> EquivClasses<T>
+{
+    /// Use the given `table`, e.g. one that cannot be created by `Default`.
+    pub(crate) fn new(table: T) -> Self
+    {
+        Self { table }
     }
 
     /// Check if the given node IDs are already known to be equivalent.  If not, they will be made
@@ -284,30 +403,19 @@ impl<T: Table> EquivClasses<T>
         &mut self,
         ak: &<T::Node as Node>::Id,
         bk: &<T::Node as Node>::Id,
-    ) -> bool
+    ) -> Result<bool, T::Error>
     {
-        match (self.table.get(ak), self.table.get(bk)) {
-            (None, None) => {
-                self.none_seen(ak, bk);
-                false
-            },
-            (Some(ac), None) => {
-                let ac = &T::Rc::clone(ac); // To end borrow of `self`.
-                self.some_seen(ac, bk);
-                false
-            },
-            (None, Some(bc)) => {
-                let bc = &T::Rc::clone(bc); // To end borrow of `self`.
-                self.some_seen(bc, ak);
-                false
-            },
-            (Some(ac), Some(bc)) => Self::all_seen(ac, bc),
-        }
+        self.table.same_class(ak, bk)
+    }
+
+    /// Forget all recorded nodes, keeping the table's allocated capacity.
+    pub(crate) fn clear(&mut self)
+    {
+        self.table.clear();
     }
 }
 
 
-#[cfg(any(feature = "alloc", feature = "std"))]
 /// Items made for ready use as specific choices for the generic types of the equivalence classes
 /// mechanisms.
 pub mod premade
@@ -317,163 +425,1079 @@ pub mod premade
     #[cfg(feature = "std")]
     pub use std::*;
 
+    /// Default of the initial capacity of the premade tables that have one, and of
+    /// [`Config::table_capacity`](crate::cycle_safe::modes::interleave::Config::table_capacity).
+    pub(crate) const INITIAL_CAPACITY: usize = 2_usize.pow(12);
+
     #[cfg(feature = "alloc")]
-    mod alloc
-    {
-        //! Support for [`alloc`] things.
+    pub use default_table::{
+        DefaultParams,
+        DefaultTable,
+    };
 
-        extern crate alloc;
+    #[cfg(feature = "alloc")]
+    mod default_table
+    {
+        use {
+            super::super::{
+                UnionFind,
+                WithCapacity,
+            },
+            crate::{
+                anticipated_or_like::Infallible,
+                Node,
+            },
+            cfg_if::cfg_if,
+            core::marker::PhantomData,
+        };
 
-        /// Support for standard [`Rc`](alloc::rc::Rc).
-        pub mod rc
+        /// Node types that have a premade table chosen as the default for them, which is used by
+        /// the premade functions of the other modules.
+        ///
+        /// With the "std" feature, this is all node types, and the table is `hash_map::Table`,
+        /// which uses [`BuildIdHasher`](super::id_hasher::BuildIdHasher) when the `"id_hasher"`
+        /// feature is enabled.  Without, this is only node types whose [`Node::Id`] is [`Ord`],
+        /// and the table is [`btree_map::Table`](super::btree_map::Table).
+        pub trait DefaultTable: Node
         {
-            use {
-                super::{
-                    super::super::{
-                        Class,
-                        Rc as RcTrait,
-                    },
-                    alloc,
-                },
-                core::{
-                    cell::Cell,
-                    ops::Deref,
-                },
-            };
+            /// The default table for `Self`.
+            type Table: UnionFind<Node = Self, Error = Infallible> + Default + WithCapacity;
+        }
 
-            /// Enables standard [`Rc`](alloc::rc::Rc) to be used as an
-            /// [`equiv_classes::Rc`](RcTrait), which requires this recursive type.
-            #[derive(Clone)]
-            pub struct Rc(alloc::rc::Rc<Cell<Class<Self>>>);
+        /// The parameters of the default tables.
+        #[allow(clippy::exhaustive_structs)]
+        pub struct DefaultParams<N>(PhantomData<N>);
 
-            impl Deref for Rc
-            {
-                type Target = Cell<Class<Self>>;
+        cfg_if! {
+            if #[cfg(feature = "std")] {
+                extern crate std;
 
-                #[allow(clippy::explicit_auto_deref)]
-                #[inline]
-                fn deref(&self) -> &Self::Target
+                use super::hash_map;
+
+                impl<N: Node> hash_map::Params for DefaultParams<N>
                 {
-                    &*self.0
+                    type Node = N;
+                }
+
+                cfg_if! {
+                    if #[cfg(feature = "id_hasher")] {
+                        type BuildHasher = super::id_hasher::BuildIdHasher;
+                    }
+                    else {
+                        type BuildHasher = std::collections::hash_map::RandomState;
+                    }
+                }
+
+                impl<N: Node> DefaultTable for N
+                {
+                    type Table = hash_map::Table<DefaultParams<N>, BuildHasher>;
                 }
             }
+            else {
+                use super::btree_map;
 
-            impl RcTrait for Rc
-            {
-                #[inline]
-                fn new(val: Cell<Class<Self>>) -> Self
+                impl<N: Node> btree_map::Params for DefaultParams<N>
                 {
-                    Self(alloc::rc::Rc::new(val))
+                    type Node = N;
+                }
+
+                impl<N: Node> DefaultTable for N
+                where N::Id: Ord
+                {
+                    type Table = btree_map::Table<DefaultParams<N>>;
                 }
             }
         }
     }
 
-    #[cfg(feature = "std")]
-    mod std
+    /// A fast hasher for node IDs that are plain pointers or integers.
+    pub mod id_hasher
     {
-        //! Support for [`std`] things.
+        use core::hash::{
+            BuildHasherDefault,
+            Hasher,
+        };
 
-        extern crate std;
+        /// Multiplier of the mixing, an odd constant with well-distributed bits (from the
+        /// golden ratio).
+        const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+        /// Hasher that mixes each written integer by a rotation, an XOR, and a multiplication,
+        /// which is much faster than the default `SipHash` of `HashMap` for small keys like
+        /// pointers and integers (e.g. [`RefId`](crate::utils::RefId)).
+        ///
+        /// Not resistant to adversarial keys (i.e. hash flooding), and so should only be used
+        /// when the node IDs are not controlled by untrusted inputs.  Pointers are not
+        /// controllable.
+        #[derive(Clone, Default, Debug)]
+        #[allow(clippy::module_name_repetitions)]
+        pub struct IdHasher(u64);
+
+        impl IdHasher
+        {
+            /// Mix `word` into the state.
+            #[inline]
+            fn add(
+                &mut self,
+                word: u64,
+            )
+            {
+                self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(SEED);
+            }
+        }
 
-        /// Support for standard [`HashMap`](std::collections::HashMap).
-        pub mod hash_map
+        impl Hasher for IdHasher
         {
-            use {
-                super::{
-                    super::{
-                        super::Table as TableTrait,
-                        rc::Rc,
-                    },
-                    std,
-                },
-                crate::Node,
-                std::collections::HashMap,
-            };
+            /// The multiplication leaves the low bits of the state zero when those of the words
+            /// are, as with aligned pointers, and so the well-mixed high bits are rotated down
+            /// because hash tables choose buckets by the low bits.
+            #[inline]
+            fn finish(&self) -> u64
+            {
+                self.0.rotate_left(26)
+            }
 
-            /// Generic parameters of [`Table`] and its operations.
-            pub trait Params
+            #[inline]
+            #[allow(clippy::little_endian_bytes)] // Any fixed order is ok for hashing.
+            fn write(
+                &mut self,
+                bytes: &[u8],
+            )
             {
-                /// Amount of elements (i.e. branch (non-leaf) nodes) that a table can grow to
-                /// contain initially before reallocating.
-                ///
-                /// The default value is a balance between being somewhat large to avoid excessive
-                /// reallocations and not being too huge that it often consumes excessive memory.
-                /// If the default is not good for your use case, a custom `impl` of [`Params`]
-                /// may be made with a more-appropriate value - either smaller or larger.  Note
-                /// that the default only affects the initial capacity of the underlying
-                /// [`HashMap`], and it will still grow as large as needed regardless by
-                /// reallocating.
-                const INITIAL_CAPACITY: usize = 2_usize.pow(12);
-                /// Type of node that is recorded in the table.  Must be the same as used with the
-                /// corresponding [`equiv::Params`](crate::generic::equiv::Params).
-                type Node: Node;
+                let mut chunks = bytes.chunks_exact(8);
+                for chunk in &mut chunks {
+                    let mut word = [0; 8];
+                    word.copy_from_slice(chunk);
+                    self.add(u64::from_le_bytes(word));
+                }
+                let rest = chunks.remainder();
+                if !rest.is_empty() {
+                    let mut word = [0; 8];
+                    if let Some(prefix) = word.get_mut(.. rest.len()) {
+                        prefix.copy_from_slice(rest);
+                    }
+                    self.add(u64::from_le_bytes(word));
+                }
             }
 
-            /// Eases using standard [`HashMap`] as an [`equiv_classes::Table`](TableTrait) that
-            /// uses [`Rc`].
-            pub struct Table<P: Params>(HashMap<<P::Node as Node>::Id, Rc>);
+            #[inline]
+            fn write_u8(
+                &mut self,
+                i: u8,
+            )
+            {
+                self.add(i.into());
+            }
 
-            impl<P: Params> Default for Table<P>
+            #[inline]
+            fn write_u16(
+                &mut self,
+                i: u16,
+            )
             {
-                /// Create a new instance with capacity
-                /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY).
-                #[inline]
-                fn default() -> Self
-                {
-                    Self(HashMap::with_capacity(P::INITIAL_CAPACITY))
-                }
+                self.add(i.into());
             }
 
-            impl<P: Params> TableTrait for Table<P>
+            #[inline]
+            fn write_u32(
+                &mut self,
+                i: u32,
+            )
             {
-                type Node = P::Node;
-                type Rc = Rc;
+                self.add(i.into());
+            }
 
-                #[inline]
-                fn get(
-                    &self,
-                    k: &<Self::Node as Node>::Id,
-                ) -> Option<&Self::Rc>
-                {
-                    HashMap::get(&self.0, k)
-                }
+            #[inline]
+            fn write_u64(
+                &mut self,
+                i: u64,
+            )
+            {
+                self.add(i);
+            }
 
-                #[inline]
-                fn insert(
-                    &mut self,
-                    k: <Self::Node as Node>::Id,
-                    v: Self::Rc,
-                )
-                {
-                    drop(HashMap::insert(&mut self.0, k, v));
-                }
+            #[inline]
+            fn write_usize(
+                &mut self,
+                i: usize,
+            )
+            {
+                #![allow(clippy::as_conversions)] // `usize` is at most 64 bits.
+                self.add(i as u64);
             }
         }
-    }
-}
-
 
-#[cfg(test)]
-mod tests
-{
-    #[allow(unused_imports)]
-    use super::*;
+        /// Creates [`IdHasher`]s.  Used by the premade functions when the `"id_hasher"` feature
+        /// is enabled.
+        #[allow(clippy::module_name_repetitions)]
+        pub type BuildIdHasher = BuildHasherDefault<IdHasher>;
+    }
 
-    #[cfg(feature = "alloc")]
-    #[test]
-    fn eq_rep()
+    /// Support for a [`UnionFind`](super::UnionFind) over a caller-provided buffer, that does
+    /// not allocate, for use without `alloc`.
+    pub mod slice
     {
-        use premade::rc::Rc;
+        use {
+            super::super::UnionFind,
+            crate::{
+                anticipated_or_like::Infallible,
+                Node,
+            },
+            core::{
+                cmp::Ordering,
+                hash::{
+                    BuildHasher,
+                    BuildHasherDefault,
+                    Hash as _,
+                    Hasher,
+                },
+            },
+        };
 
-        fn rep(weight: usize) -> Rc
+        /// Generic parameters of [`Table`] and its operations.
+        pub trait Params
         {
-            Rc::new(Cell::new(Class::Representative { weight }))
+            /// Type of node that is recorded in the table.  Must be the same as used with the
+            /// corresponding [`equiv::Params`](crate::generic::equiv::Params).
+            type Node: Node;
+            /// Type that creates the hashers of node IDs, for finding their slots.
+            /// [`BuildFnvHasher`] may be used when there is no other preference.
+            type BuildHasher: BuildHasher + Default;
         }
 
+        /// A slot of the buffer of a [`Table`], that is either empty or that records a node.
+        pub struct Slot<Id>(Option<Entry<Id>>);
+
+        /// A recorded node.
+        struct Entry<Id>
         {
-            let r = &rep(0);
-            assert!(Class::eq_rep(r, r));
+            /// The ID of the node.
+            id:     Id,
+            /// Index of the slot of the parent of this node in its equivalence class.  A node is
+            /// the representative of its class when it is its own parent.
+            parent: usize,
+            /// Upper bound of the height of the tree of the class, only meaningful for
+            /// representatives.
+            rank:   u8,
+        }
+
+        impl<Id> Slot<Id>
+        {
+            /// An empty slot, e.g. for creating a buffer like `[Slot::EMPTY; 1024]`.
+            pub const EMPTY: Self = Self(None);
+        }
+
+        impl<Id> Default for Slot<Id>
+        {
+            #[inline]
+            fn default() -> Self
+            {
+                Self::EMPTY
+            }
+        }
+
+        /// A [`UnionFind`] that records nodes in a caller-provided buffer of [`Slot`]s by open
+        /// addressing (linear probing), and that represents equivalence classes by the indexes
+        /// of the slots.  Never allocates.
+        ///
+        /// The capacity is fixed to the length of the buffer, and when that is exhausted the
+        /// algorithm aborts with the [`TableFull`] error.  Since only nodes that are seen during
+        /// the "slow" phase of [`Interleave`](crate::cycle_safe::modes::interleave::Interleave)
+        /// are recorded, a buffer much smaller than the graphs can suffice.  Performance degrades
+        /// as the buffer becomes nearly full, so it should be somewhat larger than needed.
+        ///
+        /// Uses full path compression and union by rank.
+        pub struct Table<'s, P: Params>
+        {
+            /// The buffer.
+            slots:        &'s mut [Slot<<P::Node as Node>::Id>],
+            /// Creates the hashers of node IDs.
+            build_hasher: P::BuildHasher,
+        }
+
+        impl<'s, P: Params> Table<'s, P>
+        {
+            /// Create a new instance that uses `slots` as its buffer.  All the slots are made
+            /// empty, so that a buffer can be reused.
+            #[inline]
+            pub fn new(slots: &'s mut [Slot<<P::Node as Node>::Id>]) -> Self
+            {
+                let mut table = Self { slots, build_hasher: P::BuildHasher::default() };
+                UnionFind::clear(&mut table);
+                table
+            }
+
+            /// The amount of slots, i.e. the maximum amount of nodes that can be recorded.
+            #[inline]
+            #[must_use]
+            pub fn capacity(&self) -> usize
+            {
+                self.slots.len()
+            }
+
+            /// Get the index of the slot of the node, recording it in an empty slot if it was not
+            /// already, and whether it was already.
+            #[allow(clippy::indexing_slicing, clippy::as_conversions)]
+            fn index(
+                &mut self,
+                k: &<P::Node as Node>::Id,
+            ) -> Result<(usize, bool), TableFull>
+            {
+                let len = self.slots.len();
+                let mut hasher = self.build_hasher.build_hasher();
+                k.hash(&mut hasher);
+                // Truncation is ok because only the remainder is needed.
+                #[allow(clippy::cast_possible_truncation)]
+                let start = match (hasher.finish() as usize).checked_rem(len) {
+                    Some(start) => start,
+                    None => return Err(TableFull), // No slots at all.
+                };
+
+                for i in (start .. len).chain(0 .. start) {
+                    match &self.slots[i].0 {
+                        Some(entry) if entry.id == *k => return Ok((i, true)),
+                        Some(_) => {},
+                        None => {
+                            self.slots[i].0 =
+                                Some(Entry { id: k.clone(), parent: i, rank: 0 });
+                            return Ok((i, false));
+                        },
+                    }
+                }
+                Err(TableFull)
+            }
+
+            /// Get the entry of the slot at `i`, which must be occupied.
+            #[allow(clippy::indexing_slicing)]
+            fn entry(
+                &mut self,
+                i: usize,
+            ) -> &mut Entry<<P::Node as Node>::Id>
+            {
+                match &mut self.slots[i].0 {
+                    Some(entry) => entry,
+                    #[allow(clippy::unreachable)] // Only occupied slots are linked.
+                    None => unreachable!(),
+                }
+            }
+
+            /// Get the index of the representative of the class of the node at `i`, and compress
+            /// the path to it.
+            fn find(
+                &mut self,
+                i: usize,
+            ) -> usize
+            {
+                let mut root = i;
+                loop {
+                    let parent = self.entry(root).parent;
+                    if parent == root {
+                        break;
+                    }
+                    root = parent;
+                }
+
+                let mut cur = i;
+                while cur != root {
+                    let entry = self.entry(cur);
+                    cur = entry.parent;
+                    entry.parent = root;
+                }
+
+                root
+            }
+
+            /// Merge the classes of the nodes at `a` and `b`, and return whether they were
+            /// already the same.
+            fn union(
+                &mut self,
+                a: usize,
+                b: usize,
+            ) -> bool
+            {
+                let (ar, br) = (self.find(a), self.find(b));
+
+                if ar == br {
+                    true
+                }
+                else {
+                    let (a_rank, b_rank) = (self.entry(ar).rank, self.entry(br).rank);
+                    match a_rank.cmp(&b_rank) {
+                        Ordering::Less => self.entry(ar).parent = br,
+                        Ordering::Greater => self.entry(br).parent = ar,
+                        Ordering::Equal => {
+                            self.entry(br).parent = ar;
+                            self.entry(ar).rank = a_rank.saturating_add(1);
+                        },
+                    }
+                    false
+                }
+            }
+        }
+
+        impl<P: Params> UnionFind for Table<'_, P>
+        {
+            type Error = TableFull;
+            type Node = P::Node;
+
+            /// Nodes that were not seen before are immediately made members of the class of the
+            /// other, like with the other tables.
+            #[inline]
+            fn same_class(
+                &mut self,
+                ak: &<Self::Node as Node>::Id,
+                bk: &<Self::Node as Node>::Id,
+            ) -> Result<bool, Self::Error>
+            {
+                let (ai, a_seen) = self.index(ak)?;
+                let (bi, b_seen) = self.index(bk)?;
+                Ok(self.union(ai, bi) && a_seen && b_seen)
+            }
+
+            /// Makes all the slots empty again.
+            #[inline]
+            fn clear(&mut self)
+            {
+                for slot in &mut *self.slots {
+                    *slot = Slot::EMPTY;
+                }
+            }
+        }
+
+        /// The error of a [`Table`] that has no empty slot for recording another node.
+        #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+        #[allow(clippy::exhaustive_structs)]
+        pub struct TableFull;
+
+        impl From<Infallible> for TableFull
+        {
+            #[inline]
+            fn from(_: Infallible) -> Self
+            {
+                #![allow(clippy::unreachable)] // Truly unreachable.
+                unreachable!()
+            }
+        }
+
+        /// The 64-bit FNV-1a hash function.  Simple, fast for small keys, and does not require
+        /// `std` or random seeding.  Not resistant to adversarial keys.
+        #[derive(Clone, Debug)]
+        pub struct FnvHasher(u64);
+
+        impl Default for FnvHasher
+        {
+            #[inline]
+            fn default() -> Self
+            {
+                Self(0xcbf2_9ce4_8422_2325)
+            }
+        }
+
+        impl Hasher for FnvHasher
+        {
+            #[inline]
+            fn finish(&self) -> u64
+            {
+                self.0
+            }
+
+            #[inline]
+            fn write(
+                &mut self,
+                bytes: &[u8],
+            )
+            {
+                for &byte in bytes {
+                    self.0 ^= u64::from(byte);
+                    self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+                }
+            }
+        }
+
+        /// Creates [`FnvHasher`]s.
+        pub type BuildFnvHasher = BuildHasherDefault<FnvHasher>;
+    }
+
+    #[cfg(feature = "alloc")]
+    mod alloc
+    {
+        //! Support for [`alloc`] things.
+
+        extern crate alloc;
+
+        /// Support for standard [`Rc`](alloc::rc::Rc).
+        pub mod rc
+        {
+            use {
+                super::{
+                    super::super::{
+                        Class,
+                        Rc as RcTrait,
+                    },
+                    alloc,
+                },
+                core::{
+                    cell::Cell,
+                    ops::Deref,
+                },
+            };
+
+            /// Enables standard [`Rc`](alloc::rc::Rc) to be used as an
+            /// [`equiv_classes::Rc`](RcTrait), which requires this recursive type.
+            #[derive(Clone)]
+            pub struct Rc(alloc::rc::Rc<Cell<Class<Self>>>);
+
+            impl Deref for Rc
+            {
+                type Target = Cell<Class<Self>>;
+
+                #[allow(clippy::explicit_auto_deref)]
+                #[inline]
+                fn deref(&self) -> &Self::Target
+                {
+                    &*self.0
+                }
+            }
+
+            impl RcTrait for Rc
+            {
+                #[inline]
+                fn new(val: Cell<Class<Self>>) -> Self
+                {
+                    Self(alloc::rc::Rc::new(val))
+                }
+            }
+        }
+
+        /// Support for standard [`BTreeMap`](alloc::collections::BTreeMap), for when `std` is not
+        /// available.
+        pub mod btree_map
+        {
+            use {
+                super::{
+                    super::{
+                        super::{
+                            Len,
+                            Table as TableTrait,
+                            WithCapacity,
+                        },
+                        rc::Rc,
+                    },
+                    alloc,
+                },
+                crate::Node,
+                alloc::collections::BTreeMap,
+            };
+
+            /// Generic parameters of [`Table`] and its operations.
+            pub trait Params
+            {
+                /// Type of node that is recorded in the table.  Must be the same as used with the
+                /// corresponding [`equiv::Params`](crate::generic::equiv::Params).  Its
+                /// [`Node::Id`] type must also be [`Ord`].
+                type Node: Node;
+            }
+
+            /// Eases using standard [`BTreeMap`] as an [`equiv_classes::Table`](TableTrait) that
+            /// uses [`Rc`].
+            ///
+            /// Requires only [`Ord`] of the node IDs, instead of [`Hash`](core::hash::Hash), and
+            /// does not require `std`.  Lookups are logarithmic instead of constant, and so this
+            /// is usually slower than `hash_map::Table` when that is available.
+            pub struct Table<P: Params>(BTreeMap<<P::Node as Node>::Id, Rc>);
+
+            impl<P: Params> Default for Table<P>
+            {
+                #[inline]
+                fn default() -> Self
+                {
+                    Self(BTreeMap::new())
+                }
+            }
+
+            /// The `capacity` is ignored, because [`BTreeMap`] does not have such.
+            impl<P: Params> WithCapacity for Table<P>
+            {
+                #[inline]
+                fn with_capacity(_capacity: usize) -> Self
+                {
+                    Self::default()
+                }
+            }
+
+            impl<P: Params> TableTrait for Table<P>
+            where <P::Node as Node>::Id: Ord
+            {
+                type Node = P::Node;
+                type Rc = Rc;
+
+                #[inline]
+                fn get(
+                    &self,
+                    k: &<Self::Node as Node>::Id,
+                ) -> Option<&Self::Rc>
+                {
+                    BTreeMap::get(&self.0, k)
+                }
+
+                #[inline]
+                fn insert(
+                    &mut self,
+                    k: <Self::Node as Node>::Id,
+                    v: Self::Rc,
+                )
+                {
+                    drop(BTreeMap::insert(&mut self.0, k, v));
+                }
+
+                #[inline]
+                fn clear(&mut self)
+                {
+                    BTreeMap::clear(&mut self.0);
+                }
+            }
+
+            impl<P: Params> Len for Table<P>
+            where <P::Node as Node>::Id: Ord
+            {
+                #[inline]
+                fn len(&self) -> usize
+                {
+                    BTreeMap::len(&self.0)
+                }
+            }
+        }
+
+        /// Union-find structure that stores the classes contiguously, for nodes that are numbered
+        /// densely.
+        pub mod dense
+        {
+            use {
+                super::alloc::vec::Vec,
+                core::cmp::Ordering,
+            };
+
+            /// Equivalence classes of elements that are numbered densely, i.e. `0 .. len`.
+            ///
+            /// The parent of each element and the rank of each class are stored in contiguous
+            /// vectors, which avoids a separate allocation per element and pointer chasing.  Uses
+            /// full path compression and union by rank.
+            #[derive(Clone, Default, Debug)]
+            pub struct Classes
+            {
+                /// The parent of each element.  An element is the representative of its class
+                /// when it is its own parent.
+                parents: Vec<u32>,
+                /// The rank of each element, only meaningful for representatives.  An upper
+                /// bound of the height of its tree of elements, which cannot exceed 32.
+                ranks:   Vec<u8>,
+            }
+
+            #[allow(clippy::indexing_slicing)] // Panics are documented for invalid elements.
+            impl Classes
+            {
+                /// Create a new instance that has capacity for `capacity` elements before
+                /// reallocating.
+                #[inline]
+                #[must_use]
+                pub fn with_capacity(capacity: usize) -> Self
+                {
+                    Self {
+                        parents: Vec::with_capacity(capacity),
+                        ranks:   Vec::with_capacity(capacity),
+                    }
+                }
+
+                /// The amount of elements.
+                #[inline]
+                #[must_use]
+                pub fn len(&self) -> usize
+                {
+                    self.parents.len()
+                }
+
+                /// Whether there are no elements.
+                #[inline]
+                #[must_use]
+                pub fn is_empty(&self) -> bool
+                {
+                    self.parents.is_empty()
+                }
+
+                /// Add a new element, in its own new class, and return its number.
+                ///
+                /// Returns `None` if the amount of elements would exceed what `u32` can number.
+                #[inline]
+                pub fn add(&mut self) -> Option<u32>
+                {
+                    let new = u32::try_from(self.parents.len()).ok()?;
+                    self.parents.push(new);
+                    self.ranks.push(0);
+                    Some(new)
+                }
+
+                /// Get the representative of the class that `elem` is a member of.
+                ///
+                /// Compresses the path from `elem` so that all the elements along it then have
+                /// the representative as their parent.
+                ///
+                /// # Panics
+                /// If `elem` was not returned by [`add`](Self::add).
+                #[inline]
+                #[must_use]
+                pub fn find(
+                    &mut self,
+                    elem: u32,
+                ) -> u32
+                {
+                    let mut root = elem;
+                    loop {
+                        let parent = self.parents[index(root)];
+                        if parent == root {
+                            break;
+                        }
+                        root = parent;
+                    }
+
+                    let mut cur = elem;
+                    while cur != root {
+                        let next = self.parents[index(cur)];
+                        self.parents[index(cur)] = root;
+                        cur = next;
+                    }
+
+                    root
+                }
+
+                /// Make `a` and `b` be members of the same class, merging their classes if
+                /// needed.
+                ///
+                /// Returns `true` if they were already members of the same class, else `false`.
+                ///
+                /// # Panics
+                /// If `a` or `b` were not returned by [`add`](Self::add).
+                #[inline]
+                pub fn union(
+                    &mut self,
+                    a: u32,
+                    b: u32,
+                ) -> bool
+                {
+                    let (ar, br) = (self.find(a), self.find(b));
+
+                    if ar == br {
+                        true
+                    }
+                    else {
+                        let (a_rank, b_rank) = (self.ranks[index(ar)], self.ranks[index(br)]);
+                        match a_rank.cmp(&b_rank) {
+                            Ordering::Less => self.parents[index(ar)] = br,
+                            Ordering::Greater => self.parents[index(br)] = ar,
+                            Ordering::Equal => {
+                                self.parents[index(br)] = ar;
+                                self.ranks[index(ar)] = a_rank.saturating_add(1);
+                            },
+                        }
+                        false
+                    }
+                }
+
+                /// Remove all elements.  Keeps the allocated capacity.
+                #[inline]
+                pub fn clear(&mut self)
+                {
+                    self.parents.clear();
+                    self.ranks.clear();
+                }
+            }
+
+            /// Convert an element number to an index of the vectors.
+            #[allow(clippy::as_conversions)] // `usize` is at least 32 bits where `alloc` is.
+            fn index(elem: u32) -> usize
+            {
+                elem as usize
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod std
+    {
+        //! Support for [`std`] things.
+
+        extern crate std;
+
+        /// Support for standard [`HashMap`](std::collections::HashMap).
+        pub mod hash_map
+        {
+            use {
+                super::{
+                    super::{
+                        super::{
+                            Len,
+                            Table as TableTrait,
+                            WithCapacity,
+                        },
+                        rc::Rc,
+                    },
+                    std,
+                },
+                crate::Node,
+                core::hash::BuildHasher,
+                std::collections::{
+                    hash_map::RandomState,
+                    HashMap,
+                },
+            };
+
+            /// Generic parameters of [`Table`] and its operations.
+            pub trait Params
+            {
+                /// Amount of elements (i.e. branch (non-leaf) nodes) that a table can grow to
+                /// contain initially before reallocating.
+                ///
+                /// The default value is a balance between being somewhat large to avoid excessive
+                /// reallocations and not being too huge that it often consumes excessive memory.
+                /// If the default is not good for your use case, a custom `impl` of [`Params`]
+                /// may be made with a more-appropriate value - either smaller or larger.  Note
+                /// that the default only affects the initial capacity of the underlying
+                /// [`HashMap`], and it will still grow as large as needed regardless by
+                /// reallocating.
+                const INITIAL_CAPACITY: usize = super::super::INITIAL_CAPACITY;
+                /// Type of node that is recorded in the table.  Must be the same as used with the
+                /// corresponding [`equiv::Params`](crate::generic::equiv::Params).
+                type Node: Node;
+            }
+
+            /// Eases using standard [`HashMap`] as an [`equiv_classes::Table`](TableTrait) that
+            /// uses [`Rc`].
+            ///
+            /// The `S` type creates the hashers of the node IDs.  It is a type parameter, with
+            /// [`HashMap`]'s default, instead of an associated type of [`Params`], because
+            /// defaults of associated types are not stable yet.  When the IDs are plain pointers
+            /// or integers (e.g. [`RefId`](crate::utils::RefId)) and the inputs are trusted,
+            /// [`BuildIdHasher`](super::super::id_hasher::BuildIdHasher) is faster.
+            pub struct Table<P: Params, S = RandomState>(HashMap<<P::Node as Node>::Id, Rc, S>);
+
+            impl<P: Params, S: BuildHasher + Default> Default for Table<P, S>
+            {
+                /// Create a new instance with capacity
+                /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY).
+                #[inline]
+                fn default() -> Self
+                {
+                    Self::with_capacity(P::INITIAL_CAPACITY)
+                }
+            }
+
+            impl<P: Params, S: BuildHasher + Default> WithCapacity for Table<P, S>
+            {
+                #[inline]
+                fn with_capacity(capacity: usize) -> Self
+                {
+                    Self(HashMap::with_capacity_and_hasher(capacity, S::default()))
+                }
+            }
+
+            impl<P: Params, S: BuildHasher + Default> TableTrait for Table<P, S>
+            {
+                type Node = P::Node;
+                type Rc = Rc;
+
+                #[inline]
+                fn get(
+                    &self,
+                    k: &<Self::Node as Node>::Id,
+                ) -> Option<&Self::Rc>
+                {
+                    HashMap::get(&self.0, k)
+                }
+
+                #[inline]
+                fn insert(
+                    &mut self,
+                    k: <Self::Node as Node>::Id,
+                    v: Self::Rc,
+                )
+                {
+                    drop(HashMap::insert(&mut self.0, k, v));
+                }
+
+                /// Keeps the allocated capacity, so that reusing the table does not reallocate.
+                #[inline]
+                fn clear(&mut self)
+                {
+                    HashMap::clear(&mut self.0);
+                }
+            }
+
+            impl<P: Params, S: BuildHasher + Default> Len for Table<P, S>
+            {
+                #[inline]
+                fn len(&self) -> usize
+                {
+                    HashMap::len(&self.0)
+                }
+            }
+        }
+
+        /// Support for a [`UnionFind`](super::super::UnionFind) that numbers nodes densely and
+        /// stores their classes contiguously.
+        pub mod arena
+        {
+            use {
+                super::{
+                    super::{
+                        super::{
+                            UnionFind,
+                            WithCapacity,
+                        },
+                        dense::Classes,
+                    },
+                    std,
+                },
+                crate::{
+                    anticipated_or_like::Infallible,
+                    Node,
+                },
+                core::hash::BuildHasher,
+                std::collections::{
+                    hash_map::RandomState,
+                    HashMap,
+                },
+            };
+
+            /// Generic parameters of [`Table`] and its operations.
+            pub trait Params
+            {
+                /// Amount of nodes that a table can grow to contain initially before
+                /// reallocating.  Like the `INITIAL_CAPACITY` of
+                /// [`hash_map::Params`](super::hash_map::Params).
+                const INITIAL_CAPACITY: usize = super::super::INITIAL_CAPACITY;
+                /// Type of node that is recorded in the table.  Must be the same as used with the
+                /// corresponding [`equiv::Params`](crate::generic::equiv::Params).
+                type Node: Node;
+            }
+
+            /// A [`UnionFind`] that gives each node, when first seen, a number that is dense for
+            /// the invocation of the algorithm, and that stores the classes, by those numbers,
+            /// contiguously in [`Classes`].
+            ///
+            /// Unlike [`hash_map::Table`](super::hash_map::Table), this does not allocate for
+            /// each node separately and does not chase pointers to find classes, which is faster
+            /// and uses much less memory for graphs with very many nodes.
+            ///
+            /// Supports up to `u32::MAX` nodes per invocation, which should be plenty since that
+            /// would require dozens of gigabytes just for the table.
+            ///
+            /// The `S` type creates the hashers of the node IDs, like with
+            /// [`hash_map::Table`](super::hash_map::Table).
+            pub struct Table<P: Params, S = RandomState>
+            {
+                /// Associates nodes by ID with their number.
+                numbers: HashMap<<P::Node as Node>::Id, u32, S>,
+                /// The classes of the numbered nodes.
+                classes: Classes,
+            }
+
+            impl<P: Params, S: BuildHasher + Default> Default for Table<P, S>
+            {
+                /// Create a new instance with capacity
+                /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY).
+                #[inline]
+                fn default() -> Self
+                {
+                    Self::with_capacity(P::INITIAL_CAPACITY)
+                }
+            }
+
+            impl<P: Params, S: BuildHasher + Default> WithCapacity for Table<P, S>
+            {
+                #[inline]
+                fn with_capacity(capacity: usize) -> Self
+                {
+                    Self {
+                        numbers: HashMap::with_capacity_and_hasher(capacity, S::default()),
+                        classes: Classes::with_capacity(capacity),
+                    }
+                }
+            }
+
+            impl<P: Params, S: BuildHasher> Table<P, S>
+            {
+                /// Get the number of the node, numbering it if it was not already.
+                #[allow(clippy::expect_used)] // Exceeding `u32::MAX` nodes is unrealistic.
+                fn number(
+                    &mut self,
+                    k: &<P::Node as Node>::Id,
+                ) -> (u32, bool)
+                {
+                    if let Some(&n) = self.numbers.get(k) {
+                        (n, true)
+                    }
+                    else {
+                        let n = self.classes.add().expect("at most `u32::MAX` nodes");
+                        let old = self.numbers.insert(k.clone(), n);
+                        debug_assert!(old.is_none(), "not already numbered");
+                        (n, false)
+                    }
+                }
+            }
+
+            impl<P: Params, S: BuildHasher> UnionFind for Table<P, S>
+            {
+                type Error = Infallible;
+                type Node = P::Node;
+
+                /// Nodes that were not seen before are immediately made members of the class of
+                /// the other, like with [`hash_map::Table`](super::hash_map::Table).
+                #[inline]
+                fn same_class(
+                    &mut self,
+                    ak: &<Self::Node as Node>::Id,
+                    bk: &<Self::Node as Node>::Id,
+                ) -> Result<bool, Self::Error>
+                {
+                    let (an, a_seen) = self.number(ak);
+                    let (bn, b_seen) = self.number(bk);
+                    // Unseen nodes were not already equivalent, even if `ak == bk`, like with
+                    // `hash_map::Table`.
+                    Ok(self.classes.union(an, bn) && a_seen && b_seen)
+                }
+
+                /// Keeps the allocated capacity, so that reusing the table does not reallocate.
+                #[inline]
+                fn clear(&mut self)
+                {
+                    self.numbers.clear();
+                    self.classes.clear();
+                }
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eq_rep()
+    {
+        use premade::rc::Rc;
+
+        fn rep(weight: usize) -> Rc
+        {
+            Rc::new(Cell::new(Class::Representative { weight }))
+        }
+
+        {
+            let r = &rep(0);
+            assert!(Class::eq_rep(r, r));
         }
         {
             let r1 = rep(2);
@@ -520,13 +1544,129 @@ mod tests
         assert!(!Class::eq_rep(&Class::get_rep(&link7), &rep1));
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn dense_classes()
+    {
+        use premade::dense::Classes;
+
+        let mut c = Classes::default();
+        let mut elems = [0_u32; 6];
+        for elem in &mut elems {
+            *elem = c.add().unwrap();
+        }
+        assert_eq!(c.len(), 6);
+
+        assert!(!c.union(elems[0], elems[1]));
+        assert!(!c.union(elems[2], elems[3]));
+        assert!(!c.union(elems[3], elems[4]));
+        assert!(c.union(elems[2], elems[4]));
+        assert_ne!(c.find(elems[0]), c.find(elems[2]));
+        assert!(!c.union(elems[1], elems[4]));
+        assert!(c.union(elems[0], elems[3]));
+        assert_eq!(c.find(elems[5]), elems[5]);
+
+        let rep = c.find(elems[0]);
+        assert!(elems[.. 5].iter().all(|&e| c.find(e) == rep));
+
+        c.clear();
+        assert!(c.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    struct CharKeyed;
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::unreachable)]
+    impl Node for CharKeyed
+    {
+        type Cmp = bool;
+        type Id = char;
+        type Index = u8;
+
+        fn id(&self) -> Self::Id
+        {
+            unreachable!()
+        }
+
+        fn get_edge(
+            &self,
+            _index: &Self::Index,
+        ) -> Option<Self>
+        {
+            unreachable!()
+        }
+
+        fn equiv_modulo_edges(
+            &self,
+            _other: &Self,
+        ) -> bool
+        {
+            unreachable!()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn check_same_class<T: UnionFind<Node = CharKeyed>>(mut ec: EquivClasses<T>)
+    {
+        let mut same = |a, b| {
+            let result = ec.same_class(a, b);
+            assert!(result.is_ok());
+            matches!(result, Ok(true))
+        };
+        let keys = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+
+        assert!(!same(&keys[0], &keys[1]));
+        assert!(same(&keys[0], &keys[1]));
+
+        assert!(!same(&keys[0], &keys[2]));
+        assert!(same(&keys[0], &keys[2]));
+        assert!(same(&keys[1], &keys[2]));
+
+        assert!(!same(&keys[3], &keys[2]));
+        assert!(same(&keys[3], &keys[2]));
+        assert!(same(&keys[3], &keys[1]));
+        assert!(same(&keys[3], &keys[0]));
+
+        assert!(!same(&keys[4], &keys[5]));
+        assert!(same(&keys[4], &keys[5]));
+
+        assert!(!same(&keys[5], &keys[6]));
+        assert!(same(&keys[5], &keys[6]));
+        assert!(same(&keys[4], &keys[6]));
+
+        assert!(!same(&keys[1], &keys[4]));
+        assert!(same(&keys[1], &keys[4]));
+        assert!(same(&keys[1], &keys[5]));
+        assert!(same(&keys[1], &keys[6]));
+
+        for a in &keys {
+            for b in &keys {
+                assert!(same(a, b));
+            }
+        }
+
+        // Cleared tables forget all nodes.
+        ec.clear();
+        assert_eq!(ec.same_class(&keys[0], &keys[1]).ok(), Some(false));
+        assert_eq!(ec.same_class(&keys[0], &keys[1]).ok(), Some(true));
+        assert_eq!(ec.same_class(&keys[0], &keys[2]).ok(), Some(false));
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn same_class()
     {
-        use premade::hash_map::{
-            Params,
-            Table,
+        use {
+            super::{
+                Len,
+                Table as TableTrait,
+            },
+            premade::hash_map::{
+                Params,
+                Table,
+            },
         };
 
         struct Args;
@@ -536,68 +1676,108 @@ mod tests
             type Node = CharKeyed;
         }
 
-        struct CharKeyed;
+        check_same_class(EquivClasses::<Table<Args>>::default());
 
-        #[allow(clippy::unreachable)]
-        impl Node for CharKeyed
-        {
-            type Cmp = bool;
-            type Id = char;
-            type Index = u8;
+        let mut table = Table::<Args>::default();
+        assert!(Len::is_empty(&table));
+        assert_eq!(UnionFind::same_class(&mut table, &'a', &'b').ok(), Some(false));
+        assert_eq!(UnionFind::same_class(&mut table, &'a', &'c').ok(), Some(false));
+        assert_eq!(Len::len(&table), 3);
+        TableTrait::clear(&mut table);
+        assert!(Len::is_empty(&table));
+    }
 
-            fn id(&self) -> Self::Id
-            {
-                unreachable!()
-            }
+    #[cfg(feature = "std")]
+    #[test]
+    fn arena_same_class()
+    {
+        use premade::arena::{
+            Params,
+            Table,
+        };
 
-            fn get_edge(
-                &self,
-                _index: &Self::Index,
-            ) -> Option<Self>
-            {
-                unreachable!()
-            }
+        struct Args;
 
-            fn equiv_modulo_edges(
-                &self,
-                _other: &Self,
-            ) -> bool
-            {
-                unreachable!()
-            }
+        impl Params for Args
+        {
+            type Node = CharKeyed;
         }
 
-        let mut ec = EquivClasses::<Table<Args>>::default();
-        let keys = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+        check_same_class(EquivClasses::<Table<Args>>::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn slice_same_class()
+    {
+        use premade::slice::{
+            BuildFnvHasher,
+            Params,
+            Slot,
+            Table,
+            TableFull,
+        };
+
+        struct Args;
 
-        assert!(!ec.same_class(&keys[0], &keys[1]));
-        assert!(ec.same_class(&keys[0], &keys[1]));
+        impl Params for Args
+        {
+            type BuildHasher = BuildFnvHasher;
+            type Node = CharKeyed;
+        }
 
-        assert!(!ec.same_class(&keys[0], &keys[2]));
-        assert!(ec.same_class(&keys[0], &keys[2]));
-        assert!(ec.same_class(&keys[1], &keys[2]));
+        let mut slots = [Slot::EMPTY; 7];
+        check_same_class(EquivClasses::new(Table::<Args>::new(&mut slots)));
 
-        assert!(!ec.same_class(&keys[3], &keys[2]));
-        assert!(ec.same_class(&keys[3], &keys[2]));
-        assert!(ec.same_class(&keys[3], &keys[1]));
-        assert!(ec.same_class(&keys[3], &keys[0]));
+        // Reusing the buffer starts with all slots empty.
+        let mut reused = Table::<Args>::new(&mut slots);
+        assert_eq!(reused.capacity(), 7);
+        for (a, b) in ['a', 'b', 'c', 'd', 'e', 'f', 'g'].iter().zip("bcdefgh".chars()) {
+            match (a, b) {
+                ('g', 'h') => assert_eq!(reused.same_class(a, &b), Err(TableFull)),
+                _ => assert_eq!(reused.same_class(a, &b), Ok(false)),
+            }
+        }
+        assert_eq!(reused.same_class(&'a', &'g'), Ok(true));
 
-        assert!(!ec.same_class(&keys[4], &keys[5]));
-        assert!(ec.same_class(&keys[4], &keys[5]));
+        let mut empty = Table::<Args>::new(&mut []);
+        assert_eq!(empty.same_class(&'a', &'b'), Err(TableFull));
+    }
 
-        assert!(!ec.same_class(&keys[5], &keys[6]));
-        assert!(ec.same_class(&keys[5], &keys[6]));
-        assert!(ec.same_class(&keys[4], &keys[6]));
+    #[test]
+    fn id_hasher()
+    {
+        use {
+            core::hash::{
+                Hash,
+                Hasher as _,
+            },
+            premade::id_hasher::IdHasher,
+        };
 
-        assert!(!ec.same_class(&keys[1], &keys[4]));
-        assert!(ec.same_class(&keys[1], &keys[4]));
-        assert!(ec.same_class(&keys[1], &keys[5]));
-        assert!(ec.same_class(&keys[1], &keys[6]));
+        fn hash<T: Hash>(x: T) -> u64
+        {
+            let mut hasher = IdHasher::default();
+            x.hash(&mut hasher);
+            hasher.finish()
+        }
 
-        for a in &keys {
-            for b in &keys {
-                assert!(ec.same_class(a, b));
+        assert_eq!(hash(42_usize), hash(42_usize));
+        assert_ne!(hash(42_usize), hash(43_usize));
+        assert_ne!(hash((1_u32, 2_u32)), hash((2_u32, 1_u32)));
+        assert_ne!(hash(0x1000_usize), hash(0x1008_usize));
+        assert_eq!(hash("abcdefghijk"), hash("abcdefghijk"));
+        assert_ne!(hash("abcdefghijk"), hash("abcdefghijj"));
+
+        // Aligned addresses are spread across the low bits, which choose the buckets.
+        let mut buckets = [false; 128];
+        for address in (0x1000_usize ..).step_by(8).take(buckets.len()) {
+            let bucket = usize::try_from(hash(address) & 0x7f).ok();
+            if let Some(used) = bucket.and_then(|bucket| buckets.get_mut(bucket)) {
+                *used = true;
             }
         }
+        let used = buckets.iter().filter(|&&used| used).count();
+        assert!(used.saturating_mul(2) > buckets.len());
     }
 }
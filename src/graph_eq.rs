@@ -0,0 +1,277 @@
+//! A wrapper that gives the standard comparison traits to graph types, by a chosen variation of
+//! the algorithm, so that graphs can be used directly with `==`, `assert_eq!`, sorting, and as
+//! the keys of maps and sets.
+
+use {
+    crate::{
+        basic,
+        cycle_safe,
+        deep_safe,
+        generic::equiv_classes::premade::DefaultTable,
+        robust,
+        utils::{
+            fingerprint,
+            NodeHash,
+            FINGERPRINT_LIMIT,
+        },
+        wide_safe,
+        Cmp as _,
+        Node,
+    },
+    core::{
+        cmp::Ordering,
+        fmt::{
+            self,
+            Debug,
+            Formatter,
+        },
+        hash::{
+            Hash,
+            Hasher,
+        },
+        marker::PhantomData,
+        ops::Deref,
+    },
+};
+
+
+/// Which premade variation of the algorithm is used, by [`GraphEq`], to compare nodes of type
+/// `N`.
+pub trait Strategy<N: Node>
+{
+    /// Compare the graphs rooted at `a` and `b`.
+    fn equiv(
+        a: N,
+        b: N,
+    ) -> N::Cmp;
+}
+
+/// Uses [`basic::equiv`], for graphs that are not cyclic, degenerate, nor very-deep.
+#[derive(Copy, Clone, Default, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Basic;
+
+/// Uses [`cycle_safe::equiv`], for graphs that are not very-deep.
+#[derive(Copy, Clone, Default, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct CycleSafe;
+
+/// Uses [`deep_safe::equiv`], for graphs that are not cyclic nor degenerate.
+#[derive(Copy, Clone, Default, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct DeepSafe;
+
+/// Uses [`wide_safe::equiv`], for graphs that are not cyclic nor degenerate.
+#[derive(Copy, Clone, Default, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct WideSafe;
+
+/// Uses [`robust::equiv`], for any graphs.
+#[derive(Copy, Clone, Default, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Robust;
+
+impl<N: Node> Strategy<N> for Basic
+{
+    #[inline]
+    fn equiv(
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        basic::equiv(a, b)
+    }
+}
+
+impl<N: DefaultTable> Strategy<N> for CycleSafe
+{
+    #[inline]
+    fn equiv(
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        cycle_safe::equiv(a, b)
+    }
+}
+
+impl<N: Node> Strategy<N> for DeepSafe
+{
+    #[inline]
+    fn equiv(
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        deep_safe::equiv(a, b)
+    }
+}
+
+impl<N: Node> Strategy<N> for WideSafe
+{
+    #[inline]
+    fn equiv(
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        wide_safe::equiv(a, b)
+    }
+}
+
+impl<N: DefaultTable> Strategy<N> for Robust
+{
+    #[inline]
+    fn equiv(
+        a: N,
+        b: N,
+    ) -> N::Cmp
+    {
+        robust::equiv(a, b)
+    }
+}
+
+
+/// Wrapper of a graph type `T`, where `&T` is a [`Node`] type, that implements [`PartialEq`]
+/// and [`Eq`] by comparing the graphs with the [`Strategy`] `S`.
+///
+/// When the [`Node::Cmp`] is [`Ordering`], this also implements [`PartialOrd`] and [`Ord`],
+/// which is a total order when the graphs are ordered consistently by their traversal (e.g.
+/// lexicographically for trees).  When `&T` is a [`NodeHash`] type, this also implements
+/// [`Hash`], by the [fingerprint](fingerprint()) of the first [`FINGERPRINT_LIMIT`] nodes, which
+/// is consistent with the comparison.
+///
+/// This avoids needing to `impl` those traits for each graph type, e.g. to use graphs as the
+/// keys of a `BTreeMap` or `HashMap`, with `assert_eq!`, or with `sort`.
+#[repr(transparent)]
+pub struct GraphEq<T, S = Robust>
+{
+    /// The wrapped graph.
+    graph:    T,
+    /// The chosen variation.
+    strategy: PhantomData<S>,
+}
+
+impl<T, S> GraphEq<T, S>
+{
+    /// Wrap `graph`.
+    #[inline]
+    pub const fn new(graph: T) -> Self
+    {
+        Self { graph, strategy: PhantomData }
+    }
+
+    /// Unwrap the graph.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // Not possible while `T` might need dropping.
+    pub fn into_inner(self) -> T
+    {
+        self.graph
+    }
+}
+
+impl<T, S> From<T> for GraphEq<T, S>
+{
+    #[inline]
+    fn from(graph: T) -> Self
+    {
+        Self::new(graph)
+    }
+}
+
+impl<T, S> Deref for GraphEq<T, S>
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target
+    {
+        &self.graph
+    }
+}
+
+impl<T: Clone, S> Clone for GraphEq<T, S>
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        Self::new(self.graph.clone())
+    }
+}
+
+impl<T: Debug, S> Debug for GraphEq<T, S>
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        f.debug_tuple("GraphEq").field(&self.graph).finish()
+    }
+}
+
+impl<T, S> PartialEq for GraphEq<T, S>
+where
+    for<'t> &'t T: Node,
+    S: for<'t> Strategy<&'t T>,
+{
+    #[inline]
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool
+    {
+        S::equiv(&self.graph, &other.graph).is_equiv()
+    }
+}
+
+impl<T, S> Eq for GraphEq<T, S>
+where
+    for<'t> &'t T: Node,
+    S: for<'t> Strategy<&'t T>,
+{
+}
+
+impl<T, S> PartialOrd for GraphEq<T, S>
+where
+    for<'t> &'t T: Node<Cmp = Ordering>,
+    S: for<'t> Strategy<&'t T>,
+{
+    #[inline]
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, S> Ord for GraphEq<T, S>
+where
+    for<'t> &'t T: Node<Cmp = Ordering>,
+    S: for<'t> Strategy<&'t T>,
+{
+    #[inline]
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering
+    {
+        S::equiv(&self.graph, &other.graph)
+    }
+}
+
+impl<T, S> Hash for GraphEq<T, S>
+where for<'t> &'t T: NodeHash
+{
+    #[inline]
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    )
+    {
+        fingerprint(&self.graph, FINGERPRINT_LIMIT, state);
+    }
+}
@@ -90,7 +90,7 @@
 )]
 
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 /// Items that are safe for cyclic, degenerate, and very-deep graphs.
 pub mod robust;
 
@@ -116,9 +116,36 @@ pub mod basic;
 /// addition to safety for various graph shapes.
 pub mod generic;
 
+/// Items for comparing graphs of two different node types, with any of the variations.
+pub mod cross;
+
+#[cfg(feature = "alloc")]
+/// Items for giving the standard comparison traits to graph types, with any of the premade
+/// variations.
+pub mod graph_eq;
+
+#[cfg(feature = "std")]
+/// Items for unifying graphs that contain variables, which is safe for cyclic, degenerate, and
+/// very-deep graphs.
+pub mod unify;
+
+#[cfg(feature = "alloc")]
+/// Items for comparing graphs whose nodes are accessed asynchronously, which is safe for cyclic,
+/// degenerate, and very-deep graphs.
+pub mod asynchronous;
+
+#[cfg(feature = "std")]
+/// Items for encoding graphs as compact snapshots, and for comparing snapshots without decoding
+/// them.
+pub mod snapshot;
+
 /// Miscellaneous utilities that are sometimes useful.
 pub mod utils;
 
+#[cfg(feature = "testing")]
+/// Items for checking that implementations of the traits of this crate follow their rules.
+pub mod testing;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "anticipate")] {
         /// Use of anticipated Rust features.
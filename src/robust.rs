@@ -1,9 +1,28 @@
+#[cfg(feature = "std")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+pub use witness::{
+    correspondence,
+    Correspondence,
+};
 use {
     crate::{
         anticipated_or_like::Infallible,
+        basic::modes::limited::{
+            LimitReached,
+            Limited,
+        },
         cycle_safe::modes::interleave::{
             self,
-            random::default,
+            random::{
+                default,
+                NumberGenerator,
+                SeedableNumberGenerator,
+            },
+            Config,
             Interleave,
         },
         deep_safe::recursion::{
@@ -11,50 +30,74 @@ use {
             queue::RecurQueue,
         },
         generic::{
+            combinators::{
+                self,
+                Both,
+            },
             equiv::{
                 self,
                 Equiv,
             },
-            equiv_classes::premade::hash_map,
+            equiv_classes::premade::DefaultTable,
             precheck_interleave,
         },
         Node,
     },
     core::marker::PhantomData,
 };
+#[cfg(feature = "std")]
+use {
+    crate::{
+        utils::{
+            fingerprint,
+            NodeHash,
+            FINGERPRINT_LIMIT,
+        },
+        Cmp as _,
+    },
+    alloc::{
+        vec,
+        vec::Vec,
+    },
+    core::hash::Hasher as _,
+    std::collections::{
+        hash_map::DefaultHasher,
+        HashMap,
+    },
+};
 
 #[cfg(not(feature = "anticipate"))]
 use crate::like_anticipated::IntoOk as _;
 
 
-struct Args<N>(PhantomData<N>);
+struct Args<N, R = default::RandomNumberGenerator>(PhantomData<(N, R)>);
 
-impl<N: Node> interleave::Params for Args<N>
-{
-    type Node = N;
-    type RNG = default::RandomNumberGenerator;
-    type Table = hash_map::Table<Self>;
-}
+/// Like `Args` but with an RNG that can be seeded, which the default might not be.
+type SeededArgs<N> = Args<N, default::SeededRandomNumberGenerator>;
 
-impl<N: Node> hash_map::Params for Args<N>
+impl<N: DefaultTable, R: NumberGenerator> interleave::Params for Args<N, R>
 {
     type Node = N;
+    type RNG = R;
+    type Table = N::Table;
 }
 
-impl<N: Node> recursion::queue::Params for Args<N>
+impl<N: Node, R> recursion::queue::Params for Args<N, R>
 {
     type Node = N;
 }
 
 
 /// Equivalence predicate that can handle cyclic graphs and very-deep graphs.
+///
+/// Without the "std" feature, requires the node IDs to be [`Ord`] (see [`DefaultTable`]).
 #[inline]
-pub fn equiv<N: Node>(
+pub fn equiv<N: DefaultTable>(
     a: N,
     b: N,
 ) -> N::Cmp
 {
-    impl<N: Node> equiv::Params for Args<N>
+    impl<N: DefaultTable, R: NumberGenerator> equiv::Params for Args<N, R>
     {
         type DescendMode = Interleave<Self>;
         type Error = Infallible;
@@ -68,14 +111,147 @@ pub fn equiv<N: Node>(
 }
 
 
+/// Like [`equiv`](equiv()) but uses the limits and table capacity of `config`, chosen at
+/// run-time, instead of the default constants.
+#[inline]
+pub fn equiv_with<N: DefaultTable>(
+    config: &Config,
+    a: N,
+    b: N,
+) -> N::Cmp
+{
+    let mut e = Equiv::<Args<N>>::new(Interleave::with_config(config));
+    #[allow(unstable_name_collisions)]
+    e.equiv(a, b).into_ok()
+}
+
+
+/// Like [`equiv`](equiv()) but uses an RNG created from `seed`, which determines the randomized
+/// limits of the "interleave" mode.  The same seed gives the same traversal, for reproducing it,
+/// and unpredictable seeds prevent inputs from being tuned against the constant default seed.
+#[inline]
+pub fn equiv_seeded<N: DefaultTable>(
+    seed: u64,
+    a: N,
+    b: N,
+) -> N::Cmp
+{
+    let mut e = Equiv::<SeededArgs<N>>::new(
+        Interleave::default().with_rng(SeedableNumberGenerator::from_seed(seed)),
+    );
+    #[allow(unstable_name_collisions)]
+    e.equiv(a, b).into_ok()
+}
+
+
+/// Like [`equiv`](equiv()) but aborts early when more than `limit` nodes are traversed.
+///
+/// # Errors
+/// If the limit is reached before completing, return `Err(LimitReached)`.
+#[inline]
+pub fn limited_equiv<N: DefaultTable>(
+    limit: u32,
+    a: N,
+    b: N,
+) -> Result<N::Cmp, LimitReached>
+{
+    struct LimitedArgs<N>(PhantomData<N>);
+
+    impl<N: DefaultTable> equiv::Params for LimitedArgs<N>
+    {
+        type DescendMode = Both<Limited<u32>, Interleave<Args<N>>>;
+        type Error = combinators::Error<LimitReached, Infallible>;
+        type Node = N;
+        type RecurMode = RecurQueue<Self>;
+    }
+
+    impl<N: Node> recursion::queue::Params for LimitedArgs<N>
+    {
+        type Node = N;
+    }
+
+    let mut e = Equiv::<LimitedArgs<N>>::new(Both(Limited(limit), Interleave::default()));
+    e.equiv(a, b).map_err(|error| match error {
+        combinators::Error::First(limit_reached) => limit_reached,
+        #[allow(clippy::unreachable)] // Truly unreachable.
+        combinators::Error::Second(_) => unreachable!(),
+    })
+}
+
+
+/// Like [`equiv`](equiv()) but compares multiple pairs of roots, that might share structure
+/// across the pairs, using a single table of equivalence classes and a single recursion-queue
+/// for all of them.  See [`Equiv::equiv_many`].
+#[inline]
+pub fn equiv_many<N, I>(pairs: I) -> N::Cmp
+where
+    N: DefaultTable,
+    I: IntoIterator<Item = (N, N)>,
+{
+    let mut e = Equiv::<Args<N>>::default();
+    #[allow(unstable_name_collisions)]
+    e.equiv_many(pairs).into_ok()
+}
+
+
+/// Group a collection of graphs into their equivalence classes.
+///
+/// Returns the classes as vectors of indexes into `graphs`.  The classes are ordered by their
+/// first member, and the members of each class are in ascending order.
+///
+/// To avoid comparing most pairs of graphs, each graph is first [fingerprinted](fingerprint),
+/// and a graph is only compared with the representatives of the classes that have the same
+/// fingerprint.  The comparisons use a single table of equivalence classes, which is retained
+/// across comparisons that find equivalence, so that the knowledge from previous comparisons
+/// (including of shared structure across the graphs) is exploited transitively by later
+/// comparisons.  (A comparison that finds inequivalence leaves the table with unions that are
+/// not valid, and so the table is reset after that.)
+#[cfg(feature = "std")]
+#[inline]
+pub fn partition<N: NodeHash + Clone>(graphs: &[N]) -> Vec<Vec<usize>>
+{
+    let mut classes: Vec<Vec<usize>> = Vec::new();
+    // Each fingerprint maps to the indexes of its classes and of their representatives.
+    let mut buckets: HashMap<u64, Vec<(usize, &N)>> = HashMap::new();
+    let mut e = Equiv::<Args<N>>::default();
+
+    for (index, graph) in graphs.iter().enumerate() {
+        let mut hasher = DefaultHasher::new();
+        fingerprint(graph.clone(), FINGERPRINT_LIMIT, &mut hasher);
+        let bucket = buckets.entry(hasher.finish()).or_default();
+
+        let mut found = None;
+        for &(class, representative) in &*bucket {
+            #[allow(unstable_name_collisions)]
+            let cmp = e.equiv(representative.clone(), graph.clone()).into_ok();
+            if cmp.is_equiv() {
+                found = classes.get_mut(class);
+                break;
+            }
+            e.reset();
+        }
+
+        if let Some(members) = found {
+            members.push(index);
+        }
+        else {
+            bucket.push((classes.len(), graph));
+            classes.push(vec![index]);
+        }
+    }
+
+    classes
+}
+
+
 /// Like [`equiv`](equiv()) but first tries the precheck that is faster for small acyclic graphs.
 #[inline]
-pub fn precheck_equiv<N: Node + Clone>(
+pub fn precheck_equiv<N: DefaultTable + Clone>(
     a: N,
     b: N,
 ) -> N::Cmp
 {
-    impl<N: Node> precheck_interleave::Params<N> for Args<N>
+    impl<N: DefaultTable, R: NumberGenerator> precheck_interleave::Params<N> for Args<N, R>
     {
         type Error = Infallible;
         type InterleaveParams = Self;
@@ -86,3 +262,215 @@ pub fn precheck_equiv<N: Node + Clone>(
     #[allow(unstable_name_collisions)]
     precheck_interleave::equiv::<N, Args<N>>(a, b).into_ok()
 }
+
+
+/// Like [`precheck_equiv`] but uses the limits and table capacity of `config`, chosen at
+/// run-time, instead of the default constants.
+#[inline]
+pub fn precheck_equiv_with<N: DefaultTable + Clone>(
+    config: &Config,
+    a: N,
+    b: N,
+) -> N::Cmp
+{
+    #[allow(unstable_name_collisions)]
+    precheck_interleave::equiv_with::<N, Args<N>>(config, a, b).into_ok()
+}
+
+
+/// Like [`precheck_equiv`] but uses an RNG created from `seed`, like [`equiv_seeded`].
+#[inline]
+pub fn precheck_equiv_seeded<N: DefaultTable + Clone>(
+    seed: u64,
+    a: N,
+    b: N,
+) -> N::Cmp
+{
+    #[allow(unstable_name_collisions)]
+    precheck_interleave::equiv_seeded::<N, SeededArgs<N>>(seed, a, b).into_ok()
+}
+
+
+#[cfg(feature = "std")]
+mod witness
+{
+    extern crate std;
+
+    use {
+        crate::{
+            anticipated_or_like::Infallible,
+            deep_safe::recursion::{
+                self,
+                queue::RecurQueue,
+            },
+            generic::{
+                equiv::{
+                    self,
+                    Composable,
+                    Equiv,
+                },
+                equiv_classes::{
+                    premade::DefaultTable,
+                    EquivClasses,
+                    UnionFind,
+                },
+            },
+            Cmp as _,
+            Node,
+        },
+        core::{
+            cell::RefCell,
+            marker::PhantomData,
+        },
+        std::collections::{
+            HashMap,
+            HashSet,
+        },
+    };
+
+    #[cfg(not(feature = "anticipate"))]
+    use crate::like_anticipated::IntoOk as _;
+
+    /// Which nodes of the second graph correspond to each node of the first graph, by their
+    /// [`Node::Id`]s, as given by [`correspondence`].
+    pub type Correspondence<N> = HashMap<<N as Node>::Id, HashSet<<N as Node>::Id>>;
+
+    /// Like [`equiv`](super::equiv()) but, when the graphs are equivalent, gives which nodes of
+    /// `b` correspond to each node of `a`, e.g. to transfer information that is associated with
+    /// the nodes of one graph to the other.
+    ///
+    /// Every pair of counterpart nodes is compared and recorded, including pairs of identical
+    /// nodes, which [`equiv`](super::equiv()) skips, and so every node of `a` is included.  Only
+    /// the "slow" phase of the "interleave" mode is used, so that the descendents of a pair are
+    /// traversed only once even when they are reached again via shared structure or cycles.  A
+    /// node can correspond to multiple nodes, e.g. when a cycle in `b` is an unrolling of a cycle
+    /// in `a`.
+    ///
+    /// # Errors
+    /// If the graphs are not equivalent, returns the comparison that says so.
+    #[inline]
+    pub fn correspondence<N: Node>(
+        a: N,
+        b: N,
+    ) -> Result<Correspondence<N>, N::Cmp>
+    {
+        struct Args<'c, N>(PhantomData<&'c N>);
+
+        impl<'c, N: Node> equiv::Params for Args<'c, N>
+        {
+            type DescendMode = SlowOnly<<Paired<'c, N> as DefaultTable>::Table>;
+            type Error = Infallible;
+            type Node = Paired<'c, N>;
+            type RecurMode = RecurQueue<Self>;
+        }
+
+        impl<'c, N: Node> recursion::queue::Params for Args<'c, N>
+        {
+            type Node = Paired<'c, N>;
+        }
+
+        let pairs = RefCell::new(HashMap::new());
+        let mut e = Equiv::<Args<'_, N>>::new(SlowOnly(EquivClasses::default()));
+        #[allow(unstable_name_collisions)]
+        let cmp = e
+            .equiv(Paired { node: a, side: Side::A, pairs: &pairs }, Paired {
+                node:  b,
+                side:  Side::B,
+                pairs: &pairs,
+            })
+            .into_ok();
+        drop(e);
+
+        if cmp.is_equiv() {
+            Ok(pairs.into_inner())
+        }
+        else {
+            Err(cmp)
+        }
+    }
+
+    /// Which graph a [`Paired`] node is of.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    enum Side
+    {
+        A,
+        B,
+    }
+
+    /// A node that records the pairs that it is compared in, and whose ID includes its side, so
+    /// that identical nodes are not skipped.
+    struct Paired<'c, N: Node>
+    {
+        node:  N,
+        side:  Side,
+        pairs: &'c RefCell<Correspondence<N>>,
+    }
+
+    impl<N: Node> Node for Paired<'_, N>
+    {
+        type Cmp = N::Cmp;
+        type Id = (N::Id, Side);
+        type Index = N::Index;
+
+        #[inline]
+        fn id(&self) -> Self::Id
+        {
+            (self.node.id(), self.side)
+        }
+
+        #[inline]
+        fn get_edge(
+            &self,
+            index: &Self::Index,
+        ) -> Option<Self>
+        {
+            self.node.get_edge(index).map(|node| Self {
+                node,
+                side: self.side,
+                pairs: self.pairs,
+            })
+        }
+
+        /// Records the pair if equivalent.  If the graphs are found to be not equivalent, all the
+        /// recorded pairs are discarded.
+        #[inline]
+        fn equiv_modulo_edges(
+            &self,
+            other: &Self,
+        ) -> Self::Cmp
+        {
+            let cmp = self.node.equiv_modulo_edges(&other.node);
+            if cmp.is_equiv() {
+                let (a, b) = if self.side == Side::A { (self, other) } else { (other, self) };
+                let _new =
+                    self.pairs.borrow_mut().entry(a.node.id()).or_default().insert(b.node.id());
+            }
+            cmp
+        }
+    }
+
+    /// Like the "slow" phase of the "interleave" mode, for all nodes.  The descendents of a pair
+    /// are skipped if the pair is already known to be equivalent.
+    struct SlowOnly<T>(EquivClasses<T>);
+
+    impl<N: Node, T: UnionFind<Node = N>> Composable<N> for SlowOnly<T>
+    {
+        type Error = T::Error;
+
+        #[inline]
+        fn do_edges(
+            &mut self,
+            a: &N,
+            b: &N,
+        ) -> Result<bool, Self::Error>
+        {
+            Ok(!self.0.same_class(&a.id(), &b.id())?)
+        }
+
+        #[inline]
+        fn do_traverse(&mut self) -> Result<bool, Self::Error>
+        {
+            Ok(true)
+        }
+    }
+}
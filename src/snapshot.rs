@@ -0,0 +1,318 @@
+//! A compact binary encoding of graphs, and a [`Node`] view that reads the encoding directly,
+//! e.g. to compare live graphs against snapshots of graphs that were saved to disk.
+//!
+//! A graph is encoded as a sequence of records, one per node, where each edge is the byte offset
+//! of the record of the node that it references.  Shared and cyclic nodes are encoded only once,
+//! and references to them are back-references (or forward-references) to their single record.
+//! The root is the first record.
+//!
+//! The format, where every number is a little-endian `u32`:
+//!
+//! ```text
+//! snapshot := MAGIC record+
+//! record   := value_len value_bytes edge_count edge_offset*
+//! ```
+//!
+//! The values of nodes, i.e. what [`Node::equiv_modulo_edges`] compares, are encoded by the
+//! companion trait [`EncodeValue`](crate::snapshot::EncodeValue).
+//! [`View`](crate::snapshot::View) compares the encoded values as bytes, and so values that are
+//! equivalent must be encoded as the same bytes.
+
+extern crate alloc;
+extern crate std;
+
+use {
+    crate::{
+        generic::increment_index,
+        utils::{
+            Order,
+            RefId,
+            Traverse,
+        },
+        Node,
+    },
+    alloc::vec::Vec,
+    core::cmp::Ordering,
+    std::collections::HashMap,
+};
+
+
+/// The first bytes of every snapshot, which identify the format and its version.
+pub const MAGIC: [u8; 4] = *b"gsc\x01";
+
+/// Amount of bytes of each number of the format.
+const NUM_LEN: usize = 4;
+
+/// Encoding of the values of nodes, which [`encode`] requires.
+pub trait EncodeValue: Node
+{
+    /// Append the encoding of the value of `self`, not including its edges, to `out`.
+    ///
+    /// Nodes that are equivalent modulo edges must append the same bytes, and nodes that are not
+    /// must append different bytes, for [`View`] to compare the same as the nodes.  For the
+    /// ordering to be the same, the bytes must be ordered lexicographically the same as the
+    /// values are.
+    fn encode_value(
+        &self,
+        out: &mut Vec<u8>,
+    );
+}
+
+/// [`Err`] type returned when a graph is too large for the offsets of the format.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct TooLarge;
+
+/// [`Err`] type returned when bytes are not a valid snapshot.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct Malformed
+{
+    /// The byte offset where the problem was found.
+    pub offset: usize,
+}
+
+
+/// Encode the graph rooted at `root` as a snapshot.
+///
+/// # Errors
+/// If the encoding would exceed `u32::MAX` bytes, returns [`TooLarge`].
+#[inline]
+pub fn encode<N>(root: N) -> Result<Vec<u8>, TooLarge>
+where
+    N: EncodeValue + Clone,
+    N::Index: Clone,
+{
+    let mut out = MAGIC.to_vec();
+    let mut offsets = HashMap::new();
+    let mut patches = Vec::new();
+
+    for visit in Traverse::new(root, Order::DepthFirst) {
+        let node = visit.node;
+        let _first = offsets.insert(node.id(), to_u32(out.len())?);
+
+        let value_len_at = reserve(&mut out);
+        node.encode_value(&mut out);
+        let value_len = out.len().saturating_sub(value_len_at).saturating_sub(NUM_LEN);
+        write_u32(&mut out, value_len_at, to_u32(value_len)?);
+
+        let edge_count_at = reserve(&mut out);
+        let mut edge_count = 0_u32;
+        let mut next_index = Some(N::Index::default());
+        while let Some(index) = next_index {
+            if let Some(edge) = node.get_edge(&index) {
+                patches.push((reserve(&mut out), edge.id()));
+                edge_count = edge_count.checked_add(1).ok_or(TooLarge)?;
+                next_index = increment_index(&index);
+            }
+            else {
+                break;
+            }
+        }
+        write_u32(&mut out, edge_count_at, edge_count);
+    }
+
+    for (at, id) in patches {
+        // Every edge was traversed, and so has an offset.
+        if let Some(&offset) = offsets.get(&id) {
+            write_u32(&mut out, at, offset);
+        }
+    }
+    to_u32(out.len()).map(|_| out)
+}
+
+/// Append a placeholder number, and give its offset.
+fn reserve(out: &mut Vec<u8>) -> usize
+{
+    let at = out.len();
+    out.extend_from_slice(&[0; NUM_LEN]);
+    at
+}
+
+/// Overwrite the number at the offset `at`.
+#[allow(clippy::little_endian_bytes)] // The format is little-endian regardless of the host.
+fn write_u32(
+    out: &mut [u8],
+    at: usize,
+    num: u32,
+)
+{
+    if let Some(slot) = at.checked_add(NUM_LEN).and_then(|end| out.get_mut(at .. end)) {
+        slot.copy_from_slice(&num.to_le_bytes());
+    }
+}
+
+/// Read the number at the offset `at`, if in bounds.
+fn read_u32(
+    bytes: &[u8],
+    at: usize,
+) -> Option<u32>
+{
+    let slot = bytes.get(at .. at.checked_add(NUM_LEN)?)?;
+    slot.try_into().ok().map(u32::from_le_bytes)
+}
+
+/// Convert a length or offset to the format's numbers.
+fn to_u32(len: usize) -> Result<u32, TooLarge>
+{
+    u32::try_from(len).ok().ok_or(TooLarge)
+}
+
+
+/// A node of a snapshot, which reads the encoding directly without decoding it.
+///
+/// Its [`Node::Id`] is the byte offset of its record, with the identity of the bytes, so that
+/// the nodes of different snapshots are distinct.  Its [`Node::equiv_modulo_edges`] compares the
+/// encoded values as bytes.
+#[derive(Copy, Clone, Debug)]
+pub struct View<'b>
+{
+    /// The whole snapshot, which has been validated.
+    bytes:  &'b [u8],
+    /// Where the record of this node starts.
+    offset: usize,
+}
+
+/// The parts of a record.
+struct Record<'b>
+{
+    value: &'b [u8],
+    edges: &'b [u8],
+}
+
+impl<'b> View<'b>
+{
+    /// Validate that `bytes` is a snapshot, and give a view of its root node.
+    ///
+    /// The whole snapshot is validated, once, so that reading it is always in bounds.
+    ///
+    /// # Errors
+    /// If `bytes` is not a valid snapshot, returns where the problem was found.
+    #[inline]
+    pub fn new(bytes: &'b [u8]) -> Result<Self, Malformed>
+    {
+        if bytes.get(.. MAGIC.len()) != Some(&MAGIC[..]) {
+            return Err(Malformed { offset: 0 });
+        }
+        let mut starts = Vec::new();
+        let mut edges = Vec::new();
+        let mut offset = MAGIC.len();
+        while offset < bytes.len() {
+            let record = Record::read(bytes, offset).ok_or(Malformed { offset })?;
+            starts.push(offset);
+            for (i, edge) in (0 ..).zip(record.edges.chunks_exact(NUM_LEN)) {
+                edges.push((record.edge_at(offset, i), edge));
+            }
+            offset = record.end(offset);
+        }
+        if starts.is_empty() {
+            return Err(Malformed { offset });
+        }
+        for (at, edge) in edges {
+            let target = edge.try_into().ok().map(u32::from_le_bytes).map(usize::try_from);
+            match target {
+                // The starts are sorted because they were found in sequence.
+                Some(Ok(target)) if starts.binary_search(&target).is_ok() => {},
+                _ => return Err(Malformed { offset: at }),
+            }
+        }
+        Ok(Self { bytes, offset: MAGIC.len() })
+    }
+
+    /// The encoded value of the node.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &'b [u8]
+    {
+        self.record().value
+    }
+
+    /// The amount of edges of the node.
+    #[inline]
+    #[must_use]
+    pub fn edge_count(&self) -> usize
+    {
+        self.record().edges.chunks_exact(NUM_LEN).len()
+    }
+
+    /// The record of the node, which is always valid because the snapshot was validated.
+    fn record(&self) -> Record<'b>
+    {
+        Record::read(self.bytes, self.offset).unwrap_or(Record { value: &[], edges: &[] })
+    }
+}
+
+impl<'b> Record<'b>
+{
+    /// Read the record that starts at `offset`, if it is in bounds.
+    fn read(
+        bytes: &'b [u8],
+        offset: usize,
+    ) -> Option<Self>
+    {
+        let value_len = usize::try_from(read_u32(bytes, offset)?).ok()?;
+        let value_at = offset.checked_add(NUM_LEN)?;
+        let value_end = value_at.checked_add(value_len)?;
+        let value = bytes.get(value_at .. value_end)?;
+        let edge_count = usize::try_from(read_u32(bytes, value_end)?).ok()?;
+        let edges_at = value_end.checked_add(NUM_LEN)?;
+        let edges_end = edges_at.checked_add(edge_count.checked_mul(NUM_LEN)?)?;
+        let edges = bytes.get(edges_at .. edges_end)?;
+        Some(Self { value, edges })
+    }
+
+    /// The offset after the record, which starts at `offset`.
+    fn end(
+        &self,
+        offset: usize,
+    ) -> usize
+    {
+        let lens = [NUM_LEN, self.value.len(), NUM_LEN, self.edges.len()];
+        lens.iter().fold(offset, |end, len| end.saturating_add(*len))
+    }
+
+    /// The offset of the `i`th edge of the record, which starts at `offset`.
+    fn edge_at(
+        &self,
+        offset: usize,
+        i: usize,
+    ) -> usize
+    {
+        self.end(offset)
+            .saturating_sub(self.edges.len())
+            .saturating_add(i.saturating_mul(NUM_LEN))
+    }
+}
+
+impl<'b> Node for View<'b>
+{
+    type Cmp = Ordering;
+    type Id = (RefId<&'b [u8]>, usize);
+    type Index = usize;
+
+    #[inline]
+    fn id(&self) -> Self::Id
+    {
+        (RefId(self.bytes), self.offset)
+    }
+
+    #[inline]
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        let at = index.checked_mul(NUM_LEN)?;
+        let offset = usize::try_from(read_u32(self.record().edges, at)?).ok()?;
+        Some(Self { bytes: self.bytes, offset })
+    }
+
+    #[inline]
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.value().cmp(other.value())
+    }
+}
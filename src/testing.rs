@@ -0,0 +1,224 @@
+//! Checking that [`Node`] and [`Cmp`] implementations follow the rules that are documented for
+//! them, which the algorithm relies on for giving correct results.
+//!
+//! Intended for the tests of applications.  A graph that is representative of an application's
+//! node type is traversed, and the rules are checked for each node.  Not all violations can be
+//! detected, and so no violations does not prove that an implementation is correct.
+
+extern crate alloc;
+
+use {
+    crate::{
+        generic::increment_index,
+        robust,
+        utils::{
+            Order,
+            Traverse,
+        },
+        Cmp,
+        Node,
+    },
+    alloc::vec::Vec,
+    core::cmp::Ordering,
+};
+
+
+/// The default maximum amount of nodes that [`check_node_contract`] traverses.
+pub const DEFAULT_LIMIT: usize = 10_000;
+
+/// A violation of a rule of [`Node`] or [`Cmp`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Violation<N: Node>
+{
+    /// [`Cmp::new_equiv`] does not give a value for which [`Cmp::is_equiv`] is true.
+    NewEquivNotEquiv,
+    /// [`Cmp::from_ord`] gives a value for which [`Cmp::is_equiv`] is not true exactly when the
+    /// given [`Ordering`] is [`Equal`](Ordering::Equal).
+    FromOrdInconsistent(Ordering),
+    /// [`Node::id`] gives different values for the same node, or for a clone of it.
+    UnstableId
+    {
+        /// The node.
+        node: N,
+    },
+    /// [`Node::get_edge`] gives different results when called again with the same index, i.e.
+    /// `None` and `Some`, or nodes with different IDs.
+    NondeterministicEdge
+    {
+        /// The node whose edge is given.
+        node:  N,
+        /// The index of the edge.
+        index: N::Index,
+    },
+    /// [`Node::equiv_modulo_edges`] does not give equivalence for the same node, or for a clone
+    /// of it.
+    NotReflexive
+    {
+        /// The node.
+        node: N,
+    },
+    /// [`Node::equiv_modulo_edges`] gives equivalence in one direction but not in the other,
+    /// for a node and one of its edges.
+    NotSymmetric
+    {
+        /// The node whose edge is compared with it.
+        node:  N,
+        /// The index of the edge.
+        index: N::Index,
+    },
+    /// Incrementing an index does not give a greater index, according to its [`Ord`].
+    IndexNotIncreasing
+    {
+        /// The index that was incremented.
+        index: N::Index,
+    },
+    /// Comparing the graph with itself, as if it were a separate graph, does not give
+    /// equivalence.
+    NotEquivToItself,
+}
+
+/// Check the rules of [`Node`] and [`Cmp`] for the graph reachable from `root`, and give the
+/// violations found.
+///
+/// At most [`DEFAULT_LIMIT`] nodes are traversed.  See [`check_node_contract_limited`].
+#[inline]
+pub fn check_node_contract<N>(root: N) -> Vec<Violation<N>>
+where
+    N: Node + Clone,
+    N::Index: Clone,
+{
+    check_node_contract_limited(root, DEFAULT_LIMIT)
+}
+
+/// Like [`check_node_contract`] but traverses at most `limit` nodes.
+///
+/// The limit is needed because the traversal relies on [`Node::id`] to not repeat nodes, which
+/// is not reliable when IDs are unstable.  The comparison of the graph with itself is limited
+/// similarly, and no violation is given if it reaches the limit.
+#[inline]
+pub fn check_node_contract_limited<N>(
+    root: N,
+    limit: usize,
+) -> Vec<Violation<N>>
+where
+    N: Node + Clone,
+    N::Index: Clone,
+{
+    let mut violations = Vec::new();
+    check_cmp::<N::Cmp, N>(&mut violations);
+
+    let mut index_not_increasing = false;
+    for visit in Traverse::new(root.clone(), Order::DepthFirst).take(limit) {
+        check_node(&visit.node, &mut violations, &mut index_not_increasing);
+    }
+
+    let limit = u32::try_from(limit.saturating_mul(2)).unwrap_or(u32::MAX);
+    if let Ok(cmp) =
+        robust::limited_equiv(limit, Separate(root.clone(), false), Separate(root, true))
+    {
+        if !cmp.is_equiv() {
+            violations.push(Violation::NotEquivToItself);
+        }
+    }
+    violations
+}
+
+/// Check the rules of [`Cmp`], which do not depend on any particular nodes.
+fn check_cmp<C: Cmp, N: Node>(violations: &mut Vec<Violation<N>>)
+{
+    if !C::new_equiv().is_equiv() {
+        violations.push(Violation::NewEquivNotEquiv);
+    }
+    for ord in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+        if C::from_ord(ord).is_equiv() != ord.is_eq() {
+            violations.push(Violation::FromOrdInconsistent(ord));
+        }
+    }
+}
+
+/// Check the rules of [`Node`] for a single node and its edges.
+fn check_node<N>(
+    node: &N,
+    violations: &mut Vec<Violation<N>>,
+    index_not_increasing: &mut bool,
+) where
+    N: Node + Clone,
+    N::Index: Clone,
+{
+    let clone = node.clone();
+    if !has_stable_id(node) || node.id() != clone.id() {
+        violations.push(Violation::UnstableId { node: clone.clone() });
+    }
+    if !node.equiv_modulo_edges(node).is_equiv() || !node.equiv_modulo_edges(&clone).is_equiv() {
+        violations.push(Violation::NotReflexive { node: clone.clone() });
+    }
+
+    let mut next_index = Some(N::Index::default());
+    while let Some(index) = next_index {
+        let (edge, again) = (node.get_edge(&index), node.get_edge(&index));
+        let edge = match (edge, again) {
+            // Unstable IDs of edges are violations of their own, when they are checked.
+            (Some(edge), Some(again)) if edge.id() == again.id() || !has_stable_id(&edge) => edge,
+            (None, None) => break,
+            _ => {
+                violations.push(Violation::NondeterministicEdge { node: clone, index });
+                break;
+            },
+        };
+        if node.equiv_modulo_edges(&edge).is_equiv() != edge.equiv_modulo_edges(node).is_equiv() {
+            violations
+                .push(Violation::NotSymmetric { node: clone.clone(), index: index.clone() });
+        }
+        next_index = increment_index(&index);
+        if let Some(next) = &next_index {
+            if next <= &index && !*index_not_increasing {
+                *index_not_increasing = true;
+                violations.push(Violation::IndexNotIncreasing { index });
+            }
+        }
+    }
+}
+
+/// Whether [`Node::id`] gives the same value when called again.
+fn has_stable_id<N: Node>(node: &N) -> bool
+{
+    node.id() == node.id()
+}
+
+
+/// Makes a graph be compared with itself as if it were separate, by making the IDs of its nodes
+/// different for each side, so that the comparison is not skipped for identical nodes.
+#[derive(Clone)]
+struct Separate<N>(N, bool);
+
+impl<N: Node> Node for Separate<N>
+{
+    type Cmp = N::Cmp;
+    type Id = (N::Id, bool);
+    type Index = N::Index;
+
+    #[inline]
+    fn id(&self) -> Self::Id
+    {
+        (self.0.id(), self.1)
+    }
+
+    #[inline]
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        self.0.get_edge(index).map(|edge| Self(edge, self.1))
+    }
+
+    #[inline]
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.0.equiv_modulo_edges(&other.0)
+    }
+}
@@ -0,0 +1,432 @@
+//! Unification of graphs that contain variables, e.g. the possibly-cyclic type terms of a type
+//! checker.
+//!
+//! Unifying two graphs finds bindings of their variables, to terms or to other variables, that
+//! make the graphs equivalent, i.e. a substitution, or finds that no such bindings exist because
+//! of a clash between terms.  Cyclic graphs are supported, as rational terms, and so there is no
+//! "occurs check": a variable may be bound to a term that contains the same variable.
+//!
+//! This is done by the same algorithm as the other variations, applied to nodes that resolve
+//! variables through the substitution as it is built.  The variables are recorded in a
+//! [`dense::Classes`](crate::generic::equiv_classes::premade::dense::Classes) union-find, so that
+//! variables bound to each other share a single binding.  The
+//! [`Interleave`](crate::cycle_safe::modes::interleave::Interleave) mode and the
+//! [`RecurQueue`](crate::deep_safe::recursion::queue::RecurQueue) mode are used, like with
+//! [`robust::equiv`](crate::robust::equiv), and so unification is safe for cyclic, degenerate,
+//! and very-deep graphs.
+
+extern crate alloc;
+extern crate std;
+
+use {
+    crate::{
+        anticipated_or_like::Infallible,
+        cycle_safe::modes::interleave::{
+            self,
+            random::default,
+            Interleave,
+        },
+        deep_safe::recursion::{
+            self,
+            queue::RecurQueue,
+        },
+        generic::{
+            equiv::{
+                self,
+                Equiv,
+            },
+            equiv_classes::premade::{
+                dense::Classes,
+                DefaultTable,
+            },
+            increment_index,
+        },
+        Cmp as _,
+        Node,
+    },
+    alloc::vec::Vec,
+    core::{
+        cell::RefCell,
+        hash::Hash,
+        marker::PhantomData,
+    },
+    std::collections::HashMap,
+};
+
+#[cfg(not(feature = "anticipate"))]
+use crate::like_anticipated::IntoOk as _;
+
+
+/// What unification requires from a node type, in addition to [`Node`].
+pub trait Unifiable: Node
+{
+    /// Identifies variables.  Distinct nodes that have the same variable are occurrences of the
+    /// same variable.
+    type Var: Eq + Hash + Clone;
+
+    /// Give the variable that the `self` node is, if it is one.
+    ///
+    /// A node that is a variable must not have edges, because it is replaced by the term that
+    /// it is bound to, if any.
+    fn as_variable(&self) -> Option<Self::Var>;
+}
+
+/// The bindings of variables that make unified graphs equivalent.
+///
+/// Each bound variable is mapped to the term that it is bound to, or to a node of another
+/// variable that it is bound to when it is not bound to a term.  Variables that are not bound
+/// are not included.
+///
+/// The terms may contain variables that are bound too, and so fully applying a substitution to
+/// a term requires looking up the variables in it repeatedly (which might never end for terms
+/// that are bound cyclically).
+pub type Substitution<N> = HashMap<<N as Unifiable>::Var, N>;
+
+/// Terms that cannot be unified, because they are not equivalent in their own values or in their
+/// amounts of edges.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Clash<N>
+{
+    /// The term from the first graph, with its variables resolved.
+    pub a: N,
+    /// The term from the second graph, with its variables resolved.
+    pub b: N,
+}
+
+/// Unify `a` with `b`, and give the bindings of their variables that make them equivalent.
+///
+/// # Errors
+/// If the graphs cannot be unified, returns the first [`Clash`] found.
+#[inline]
+pub fn unify<N: Unifiable + Clone>(
+    a: N,
+    b: N,
+) -> Result<Substitution<N>, Clash<N>>
+{
+    let mut unifier = Unifier::new();
+    unifier.unify(a, b)?;
+    Ok(unifier.substitution())
+}
+
+/// Unifies multiple pairs of graphs, accumulating the bindings of their variables.
+///
+/// Useful when the pairs become known one at a time, e.g. the constraints found by a type
+/// checker as it goes.
+pub struct Unifier<N: Unifiable>
+{
+    /// Shared with the [`Term`] nodes during unification.
+    state: RefCell<State<N>>,
+}
+
+impl<N: Unifiable> Default for Unifier<N>
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<N: Unifiable> Unifier<N>
+{
+    /// Create a new instance that has no bindings.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self {
+            state: RefCell::new(State {
+                numbers:   HashMap::new(),
+                variables: Vec::new(),
+                classes:   Classes::default(),
+                terms:     Vec::new(),
+                clash:     None,
+            }),
+        }
+    }
+}
+
+impl<N: Unifiable + Clone> Unifier<N>
+{
+    /// Unify `a` with `b`, in addition to all the pairs that were already unified by `self`.
+    ///
+    /// # Errors
+    /// If the graphs cannot be unified, returns the first [`Clash`] found.  The bindings that
+    /// were made before the clash was found remain, and so `self` should not be used further
+    /// unless that is acceptable.
+    #[inline]
+    pub fn unify(
+        &mut self,
+        a: N,
+        b: N,
+    ) -> Result<(), Clash<N>>
+    {
+        struct Args<'s, N>(PhantomData<&'s N>);
+
+        impl<'s, N: Unifiable + Clone> equiv::Params for Args<'s, N>
+        {
+            type DescendMode = Interleave<Self>;
+            type Error = Infallible;
+            type Node = Term<'s, N>;
+            type RecurMode = RecurQueue<Self>;
+        }
+
+        impl<'s, N: Unifiable + Clone> interleave::Params for Args<'s, N>
+        {
+            type Node = Term<'s, N>;
+            type RNG = default::RandomNumberGenerator;
+            type Table = <Term<'s, N> as DefaultTable>::Table;
+        }
+
+        impl<'s, N: Unifiable + Clone> recursion::queue::Params for Args<'s, N>
+        {
+            type Node = Term<'s, N>;
+        }
+
+        let state = &self.state;
+        let mut e = Equiv::<Args<'_, N>>::default();
+        #[allow(unstable_name_collisions)]
+        let unified = e.equiv(Term::new(a, state), Term::new(b, state)).into_ok();
+        drop(e);
+
+        match (unified, self.state.get_mut().clash.take()) {
+            (true, _) => Ok(()),
+            (false, Some(clash)) => Err(clash),
+            #[allow(clippy::unreachable)] // Every inequivalence is recorded as a clash.
+            (false, None) => unreachable!(),
+        }
+    }
+
+    /// Give the term that `node` is bound to, if it is a variable that is bound to a term.  Or
+    /// give a node of the variable that represents the variables that `node` is bound to, if it
+    /// is a variable that is bound to variables only.  Else give `node`.
+    #[inline]
+    pub fn resolve(
+        &mut self,
+        node: N,
+    ) -> N
+    {
+        self.state.get_mut().resolve(node)
+    }
+
+    /// Give the bindings of all the variables seen so far.
+    #[inline]
+    #[must_use]
+    pub fn substitution(&mut self) -> Substitution<N>
+    {
+        let state = self.state.get_mut();
+        let mut substitution = HashMap::new();
+        for (number, node) in (0 ..).zip(&state.variables) {
+            let rep = state.classes.find(number);
+            let term = state.terms.get(index(rep)).and_then(Option::as_ref);
+            let binding = if term.is_some() || rep == number {
+                term
+            }
+            else {
+                state.variables.get(index(rep))
+            };
+            if let (Some(var), Some(binding)) = (node.as_variable(), binding) {
+                drop(substitution.insert(var, binding.clone()));
+            }
+        }
+        substitution
+    }
+}
+
+
+/// The state of unification that is shared by the [`Term`] nodes.
+struct State<N: Unifiable>
+{
+    /// Associates variables with their number in `classes`.
+    numbers:   HashMap<N::Var, u32>,
+    /// The first node seen of each numbered variable.
+    variables: Vec<N>,
+    /// Which variables are bound to each other.
+    classes:   Classes,
+    /// The term that each class of variables is bound to, by the number of its representative.
+    terms:     Vec<Option<N>>,
+    /// The first clash found.
+    clash:     Option<Clash<N>>,
+}
+
+impl<N: Unifiable + Clone> State<N>
+{
+    /// Get the number of the representative of the class of `var`, numbering it if it was not
+    /// already.
+    #[allow(clippy::expect_used)] // Exceeding `u32::MAX` variables is unrealistic.
+    fn find(
+        &mut self,
+        var: N::Var,
+        node: &N,
+    ) -> u32
+    {
+        let number = if let Some(&number) = self.numbers.get(&var) {
+            number
+        }
+        else {
+            let number = self.classes.add().expect("at most `u32::MAX` variables");
+            let old = self.numbers.insert(var, number);
+            debug_assert!(old.is_none(), "not already numbered");
+            self.variables.push(node.clone());
+            self.terms.push(None);
+            number
+        };
+        self.classes.find(number)
+    }
+
+    /// Replace a variable with what it is bound to.
+    fn resolve(
+        &mut self,
+        node: N,
+    ) -> N
+    {
+        if let Some(var) = node.as_variable() {
+            let rep = self.find(var, &node);
+            let binding = self.terms.get(index(rep)).and_then(Option::as_ref);
+            binding.or_else(|| self.variables.get(index(rep))).cloned().unwrap_or(node)
+        }
+        else {
+            node
+        }
+    }
+
+    /// Bind the variables of `a` and `b`, which must be resolved, to each other or to the term
+    /// of the other.  Or check that the terms are equivalent modulo edges, recording the clash
+    /// if not.
+    fn bind(
+        &mut self,
+        a: N,
+        b: N,
+    ) -> bool
+    {
+        match (a.as_variable(), b.as_variable()) {
+            (Some(a_var), Some(b_var)) => {
+                let (a_rep, b_rep) = (self.find(a_var, &a), self.find(b_var, &b));
+                let _already = self.classes.union(a_rep, b_rep);
+                true
+            },
+            (Some(var), None) => {
+                self.bind_term(var, &a, b);
+                true
+            },
+            (None, Some(var)) => {
+                self.bind_term(var, &b, a);
+                true
+            },
+            (None, None) =>
+                if a.equiv_modulo_edges(&b).is_equiv() && same_amount_edges(&a, &b) {
+                    true
+                }
+                else {
+                    if self.clash.is_none() {
+                        self.clash = Some(Clash { a, b });
+                    }
+                    false
+                },
+        }
+    }
+
+    /// Bind the class of the unbound `var` to `term`.
+    fn bind_term(
+        &mut self,
+        var: N::Var,
+        node: &N,
+        term: N,
+    )
+    {
+        let rep = self.find(var, node);
+        if let Some(binding) = self.terms.get_mut(index(rep)) {
+            *binding = Some(term);
+        }
+    }
+}
+
+/// Whether the nodes have the same amount of edges.
+fn same_amount_edges<N: Node>(
+    a: &N,
+    b: &N,
+) -> bool
+{
+    let mut index = N::Index::default();
+    loop {
+        match (a.get_edge(&index).is_some(), b.get_edge(&index).is_some()) {
+            (true, true) =>
+                if let Some(next) = increment_index(&index) {
+                    index = next;
+                }
+                else {
+                    break true;
+                },
+            (a_has, b_has) => break a_has == b_has,
+        }
+    }
+}
+
+/// Convert a variable number to an index of the vectors.
+#[allow(clippy::as_conversions)] // `usize` is at least 32 bits where `alloc` is.
+fn index(number: u32) -> usize
+{
+    number as usize
+}
+
+
+/// A node whose variables are resolved through the [`State`], which is what the algorithm is
+/// applied to.
+struct Term<'s, N: Unifiable>
+{
+    /// Resolved when created, but might be a variable that becomes bound afterwards.
+    node:  N,
+    state: &'s RefCell<State<N>>,
+}
+
+impl<'s, N: Unifiable + Clone> Term<'s, N>
+{
+    fn new(
+        node: N,
+        state: &'s RefCell<State<N>>,
+    ) -> Self
+    {
+        Self { node: state.borrow_mut().resolve(node), state }
+    }
+
+    /// Resolve again, in case the node is a variable that became bound.
+    fn resolved(&self) -> N
+    {
+        self.state.borrow_mut().resolve(self.node.clone())
+    }
+}
+
+impl<N: Unifiable + Clone> Node for Term<'_, N>
+{
+    type Cmp = bool;
+    type Id = N::Id;
+    type Index = N::Index;
+
+    #[inline]
+    fn id(&self) -> Self::Id
+    {
+        self.node.id()
+    }
+
+    /// Gives the edges of the term that a variable is bound to.
+    #[inline]
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        self.resolved().get_edge(index).map(|edge| Self::new(edge, self.state))
+    }
+
+    /// Binds variables, which is always equivalent, or compares terms.
+    #[inline]
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        let (a, b) = (self.resolved(), other.resolved());
+        self.state.borrow_mut().bind(a, b)
+    }
+}
@@ -1,3 +1,16 @@
+#[cfg(feature = "std")]
+pub use analysis::{
+    analyze,
+    Analysis,
+    Variant,
+};
+#[cfg(feature = "std")]
+pub use cloning::deep_clone;
+#[cfg(feature = "alloc")]
+pub use fingerprint::{
+    fingerprint,
+    FINGERPRINT_LIMIT,
+};
 #[cfg(feature = "alloc")]
 pub(crate) use lazy_collections::{
     LazierIterator,
@@ -5,7 +18,43 @@ pub(crate) use lazy_collections::{
     LazyVecStack,
 };
 pub(crate) use non_advancing_iterator::NonAdvancingIterator;
-pub use ref_id::RefId;
+#[cfg(feature = "alloc")]
+pub use safe_drop::deep_safe_drop;
+#[cfg(feature = "std")]
+pub use traversal::{
+    traverse,
+    Traverse,
+    Visit,
+};
+pub use {
+    checked::{
+        Checked,
+        CheckedCmp,
+        ContractViolation,
+    },
+    cloning::NodeBuilder,
+    fingerprint::NodeHash,
+    order::Order,
+    ref_id::RefId,
+    safe_drop::TakeEdges,
+};
+
+
+mod order
+{
+    /// The order in which nodes are visited, by `Traverse` (with the `std` feature) and by
+    /// `AsyncEquiv` (with the `alloc` feature).
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    pub enum Order
+    {
+        /// Depth-first preorder, where the edges of each node are descended in increasing-index
+        /// order.
+        DepthFirst,
+        /// Breadth-first, where the edges of each node are enqueued in increasing-index order.
+        BreadthFirst,
+    }
+}
 
 
 mod ref_id
@@ -245,6 +294,861 @@ mod lazy_collections
 }
 
 
+mod fingerprint
+{
+    use {
+        crate::Node,
+        core::hash::Hasher,
+    };
+
+    /// Hashing of nodes that is consistent with their comparison, like [`Hash`](core::hash::Hash)
+    /// is with [`Eq`].
+    ///
+    /// Enables quickly separating graphs that cannot be equivalent, e.g. when partitioning a
+    /// collection of graphs into equivalence classes, so that most pairs of graphs do not need
+    /// to be compared.
+    pub trait NodeHash: Node
+    {
+        /// Feed, into `state`, the directly-contained semantically-significant values of the
+        /// `self` node, ignoring its edges and its descendent nodes.
+        ///
+        /// Must be consistent with [`Node::equiv_modulo_edges`]: when that considers two nodes
+        /// equivalent, this must feed the same data for both.  It is sufficient to feed nothing
+        /// (which makes only the shapes of graphs be distinguished), but the more of the values
+        /// that are compared that are also fed, the more that inequivalent graphs are
+        /// distinguished.
+        fn hash_modulo_edges<H: Hasher>(
+            &self,
+            state: &mut H,
+        );
+    }
+
+    /// Amount of nodes of each graph that the premade items fingerprint, i.e. the `limit` given
+    /// to [`fingerprint`] by the [`Hash`](core::hash::Hash) of `GraphEq` and by `partition`.
+    #[cfg(feature = "alloc")]
+    pub const FINGERPRINT_LIMIT: usize = 64;
+
+    /// Feed, into `state`, a fingerprint of the graph rooted at `root`.
+    ///
+    /// The fingerprint is of the first `limit` nodes of the breadth-first unfolding of the graph
+    /// (i.e. as if it were a tree where shared and cyclic nodes are repeated), which is the same
+    /// for equivalent graphs, and so equivalent graphs always have the same fingerprint.  For
+    /// each node, its values (by [`NodeHash::hash_modulo_edges`]) and its amount of edges, capped
+    /// at `limit`, are fed.  Because the amounts of nodes and of edges walked are limited, this
+    /// is safe for cyclic, very-deep, and very-wide graphs, and its cost is bounded.
+    ///
+    /// Because identical nodes (i.e. with `==` [`Node::Id`]s) are always equivalent regardless
+    /// of their values and edges, the [`NodeHash::hash_modulo_edges`] and the edges of each node
+    /// must always be the same for the same identity (which is usual).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn fingerprint<N: NodeHash, H: Hasher>(
+        root: N,
+        limit: usize,
+        state: &mut H,
+    )
+    {
+        extern crate alloc;
+        use {
+            crate::generic::increment_index,
+            alloc::collections::VecDeque,
+        };
+
+        let mut queue = VecDeque::from([root]);
+        let mut remaining = limit;
+
+        while remaining > 0 {
+            remaining = remaining.saturating_sub(1);
+
+            if let Some(node) = queue.pop_front() {
+                node.hash_modulo_edges(state);
+
+                let mut amount_edges: usize = 0;
+                let mut index = Some(N::Index::default());
+                while let Some(i) = index {
+                    match node.get_edge(&i) {
+                        // Stop at the cap, so that very-wide nodes are not walked entirely.
+                        Some(_) if amount_edges >= limit => index = None,
+                        Some(edge) => {
+                            // Only enqueue as many as could still be traversed.
+                            if queue.len() < remaining {
+                                queue.push_back(edge);
+                            }
+                            amount_edges = amount_edges.saturating_add(1);
+                            index = increment_index(&i);
+                        },
+                        None => index = None,
+                    }
+                }
+                state.write_usize(amount_edges);
+            }
+            else {
+                break;
+            }
+        }
+    }
+}
+
+
+mod safe_drop
+{
+    /// Detaching the edges of a node, so that dropping it does not recursively drop its
+    /// descendents.
+    ///
+    /// Enables `deep_safe_drop` (with the `alloc` feature), which drops graphs without
+    /// call-stack recursion, and which enables writing [`Drop`] impls that are safe for
+    /// very-deep graphs.
+    pub trait TakeEdges: Sized
+    {
+        /// Type of collection of the edges taken.
+        type Edges: IntoIterator<Item = Self>;
+
+        /// Detach the edges of the `self` node and give them, so that the node no longer refers
+        /// to them and so that dropping it afterwards does not drop them.
+        ///
+        /// A node that no longer has edges, e.g. because they were already taken, must give
+        /// none.
+        ///
+        /// For a node with shared ownership, giving no edges when it is not uniquely owned (e.g.
+        /// when `Rc::get_mut` gives `None`) avoids mutating a graph that is still referenced
+        /// elsewhere, because its last owner will take its edges when that is dropped.  But such
+        /// nodes in cycles are never uniquely owned, and so, to break cycles, their edges
+        /// must be taken regardless (e.g. with interior mutability).
+        fn take_edges(&mut self) -> Self::Edges;
+    }
+
+    /// Drop the graphs rooted at `roots`, without call-stack recursion, by iteratively taking
+    /// the edges of nodes before dropping them.
+    ///
+    /// Safe for very-deep graphs, and safe for cyclic graphs when [`TakeEdges::take_edges`]
+    /// breaks cycles.  The nodes whose edges are taken are changed to no longer have edges, and
+    /// so any other references to them see the graphs as detached.
+    ///
+    /// The traversal is depth-first, which uses little memory for shapes like lists regardless
+    /// of the order of their edges.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn deep_safe_drop<N, I>(roots: I)
+    where
+        N: TakeEdges,
+        I: IntoIterator<Item = N>,
+    {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let mut stack: Vec<N> = roots.into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.take_edges());
+            drop(node); // Now has no edges, and so does not recur.
+        }
+    }
+}
+
+
+mod cloning
+{
+    use crate::Node;
+
+    /// Creating the nodes of a new graph from the nodes of another graph.
+    ///
+    /// Enables `deep_clone` (with the `std` feature), which copies graphs, including their
+    /// shared structure and cycles, without call-stack recursion.  Because a node might be
+    /// reachable from its own edges, nodes are created without edges and their edges are added
+    /// afterwards, and so [`Output`](Self::Output) is usually a handle, e.g. an index into an
+    /// arena or an `Rc<RefCell<_>>`.
+    pub trait NodeBuilder<N: Node>
+    {
+        /// Type of the new nodes.  Cloning must give another handle to the same node.
+        type Output: Clone;
+
+        /// Create a new node with the values of `source` but without edges yet.
+        fn create(
+            &mut self,
+            source: &N,
+        ) -> Self::Output;
+
+        /// Add `edge` as the next edge of `node`.
+        ///
+        /// Called, for each node, in increasing-index order of the edges of its source node.
+        fn add_edge(
+            &mut self,
+            node: &Self::Output,
+            edge: Self::Output,
+        );
+    }
+
+    /// Copy the graph rooted at `root` into new nodes created by `builder`, and give the new
+    /// root.
+    ///
+    /// Each distinct [`Node::Id`] of the source graph is created exactly once, and so shared
+    /// structure and cycles are reproduced.  Safe for cyclic, degenerate, and very-deep graphs,
+    /// by tracking the nodes already created and by using a vector-stack instead of call-stack
+    /// recursion.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn deep_clone<N, B>(
+        root: N,
+        builder: &mut B,
+    ) -> B::Output
+    where
+        N: Node,
+        B: NodeBuilder<N>,
+    {
+        extern crate alloc;
+        extern crate std;
+        use {
+            crate::generic::increment_index,
+            alloc::vec::Vec,
+            std::collections::{
+                hash_map::Entry,
+                HashMap,
+            },
+        };
+
+        let new_root = builder.create(&root);
+        let mut created = HashMap::from([(root.id(), new_root.clone())]);
+        // The source nodes whose edges remain to be added to their new nodes.
+        let mut stack = Vec::from([(root, new_root.clone())]);
+
+        while let Some((source, node)) = stack.pop() {
+            let mut next_index = Some(N::Index::default());
+
+            while let Some(edge) = next_index.take().and_then(|index| {
+                let edge = source.get_edge(&index);
+                next_index = increment_index(&index);
+                edge
+            }) {
+                let new_edge = match created.entry(edge.id()) {
+                    Entry::Occupied(occupied) => occupied.get().clone(),
+                    Entry::Vacant(vacant) => {
+                        let new_edge = builder.create(&edge);
+                        stack.push((edge, new_edge.clone()));
+                        vacant.insert(new_edge).clone()
+                    },
+                };
+                builder.add_edge(&node, new_edge);
+            }
+        }
+        new_root
+    }
+}
+
+
+#[cfg(feature = "std")]
+mod traversal
+{
+    extern crate std;
+
+    use {
+        super::{
+            LazierIterator as _,
+            LazyVecQueue,
+            LazyVecStack,
+            NonAdvancingIterator,
+            Order,
+        },
+        crate::{
+            generic::increment_index,
+            Node,
+        },
+        std::collections::HashSet,
+    };
+
+    /// A node yielded by [`Traverse`], with its position in the traversal.
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub struct Visit<N: Node>
+    {
+        /// The node.
+        pub node:  N,
+        /// Amount of edges from the root to the node, along the path of the traversal.
+        pub depth: usize,
+        /// Index of the edge, of the parent node in the traversal, that gave the node.  `None`
+        /// for the root.
+        ///
+        /// With [`Order::DepthFirst`], the full path of indexes from the root can be maintained
+        /// by truncating a path to `depth - 1` elements and then pushing this.
+        pub index: Option<N::Index>,
+    }
+
+    /// Iterator that yields every node reachable from a root exactly once, without call-stack
+    /// recursion.
+    ///
+    /// Safe for cyclic graphs, by tracking the [`Node::Id`]s of the nodes already yielded, and
+    /// for very-deep graphs, by lazily getting edges with a vector-stack or -queue.  Nodes that
+    /// are reached multiple times, via shared structure or cycles, are yielded only at the first
+    /// time in the order.
+    pub struct Traverse<N: Node>
+    {
+        /// The root, until it is yielded.
+        root:    Option<N>,
+        /// Nodes whose edges remain to be traversed.
+        pending: Pending<N>,
+        /// The IDs of the nodes already yielded.
+        seen:    HashSet<N::Id>,
+    }
+
+    /// The container of [`Edges`] that determines the [`Order`].
+    enum Pending<N: Node>
+    {
+        DepthFirst(LazyVecStack<Edges<N>>),
+        BreadthFirst(LazyVecQueue<Edges<N>>),
+    }
+
+    /// Get the edges of a node lazily, in increasing-index order, with their positions.
+    struct Edges<N: Node>
+    {
+        node:       N,
+        /// The depth of the edges.
+        depth:      usize,
+        next_index: Option<N::Index>,
+    }
+
+    /// Create a [`Traverse`] in depth-first order from `root`.
+    #[inline]
+    pub fn traverse<N: Node + Clone>(root: N) -> Traverse<N>
+    where N::Index: Clone
+    {
+        Traverse::new(root, Order::DepthFirst)
+    }
+
+    impl<N: Node> Traverse<N>
+    where N::Index: Clone
+    {
+        /// Create a new instance that traverses from `root` in the given `order`.
+        #[inline]
+        #[must_use]
+        pub fn new(
+            root: N,
+            order: Order,
+        ) -> Self
+        {
+            let pending = match order {
+                Order::DepthFirst => Pending::DepthFirst(LazyVecStack::with_capacity(0)),
+                Order::BreadthFirst => Pending::BreadthFirst(LazyVecQueue::with_capacity(0)),
+            };
+            Self { root: Some(root), pending, seen: HashSet::new() }
+        }
+
+        /// Arrange for the edges of `node` to be traversed, if it has any.
+        fn descend(
+            &mut self,
+            node: N,
+            depth: usize,
+        )
+        {
+            let mut edges = Edges {
+                node,
+                depth: depth.saturating_add(1),
+                next_index: Some(N::Index::default()),
+            };
+            if edges.has_next() {
+                match &mut self.pending {
+                    Pending::DepthFirst(stack) => stack.extend(edges),
+                    Pending::BreadthFirst(queue) => queue.extend(edges),
+                }
+            }
+        }
+    }
+
+    impl<N: Node + Clone> Iterator for Traverse<N>
+    where N::Index: Clone
+    {
+        type Item = Visit<N>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            if let Some(root) = self.root.take() {
+                let _: bool = self.seen.insert(root.id());
+                self.descend(root.clone(), 0);
+                return Some(Visit { node: root, depth: 0, index: None });
+            }
+
+            loop {
+                let (node, depth, index) = match &mut self.pending {
+                    Pending::DepthFirst(stack) => stack.next(),
+                    Pending::BreadthFirst(queue) => queue.next(),
+                }?;
+                if self.seen.insert(node.id()) {
+                    self.descend(node.clone(), depth);
+                    return Some(Visit { node, depth, index: Some(index) });
+                }
+            }
+        }
+    }
+
+    impl<N: Node> Edges<N>
+    where N::Index: Clone
+    {
+        fn get_next(
+            &mut self,
+            advance: bool,
+        ) -> Option<<Self as Iterator>::Item>
+        {
+            let index = self.next_index.as_ref()?;
+            if let Some(edge) = self.node.get_edge(index) {
+                let index = index.clone();
+                if advance {
+                    self.next_index = increment_index(&index);
+                }
+                Some((edge, self.depth, index))
+            }
+            else {
+                if advance {
+                    self.next_index = None;
+                }
+                None
+            }
+        }
+    }
+
+    impl<N: Node> Iterator for Edges<N>
+    where N::Index: Clone
+    {
+        type Item = (N, usize, N::Index);
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            self.get_next(true)
+        }
+    }
+
+    impl<N: Node> NonAdvancingIterator for Edges<N>
+    where N::Index: Clone
+    {
+        fn next_no_adv(&mut self) -> Option<Self::Item>
+        {
+            self.get_next(false)
+        }
+    }
+}
+
+
+#[cfg(feature = "std")]
+mod analysis
+{
+    extern crate alloc;
+    extern crate std;
+
+    use {
+        crate::{
+            generic::increment_index,
+            Node,
+        },
+        alloc::vec::Vec,
+        std::collections::{
+            hash_map::Entry,
+            HashMap,
+        },
+    };
+
+    /// The shape of the graph reachable from a root, as determined by [`analyze`].
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    pub struct Analysis
+    {
+        /// Amount of distinct nodes, by [`Node::Id`], reachable from the root, including the
+        /// root.
+        pub nodes:      usize,
+        /// Amount of edges of the longest path from the root, excluding the edges that close
+        /// cycles.  For acyclic graphs, this is exactly the longest path.
+        pub max_depth:  usize,
+        /// Whether any cycle is reachable from the root.
+        pub cyclic:     bool,
+        /// The greatest amount of edges that any node has.
+        pub max_degree: usize,
+        /// Amount of nodes that are referenced more than once, by edges or, for the root, by
+        /// edges and by being the root.
+        pub shared:     usize,
+    }
+
+    /// Which of the premade variants of the `equiv` function fits the shape of a graph.
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    pub enum Variant
+    {
+        /// [`basic::equiv`](crate::basic::equiv), for shallow graphs without shared structure.
+        Basic,
+        /// [`cycle_safe::equiv`](crate::cycle_safe::equiv), for shallow graphs with shared
+        /// structure or cycles.
+        CycleSafe,
+        /// [`deep_safe::equiv`](crate::deep_safe::equiv), for deep graphs without shared
+        /// structure.
+        DeepSafe,
+        /// [`robust::equiv`](crate::robust::equiv), for deep graphs with shared structure or
+        /// cycles.
+        Robust,
+    }
+
+    impl Analysis
+    {
+        /// Fraction of the nodes that are [`shared`](Self::shared), in the range `0.0 ..= 1.0`.
+        #[inline]
+        #[must_use]
+        pub fn shared_fraction(&self) -> f64
+        {
+            #![allow(
+                clippy::as_conversions,
+                clippy::cast_precision_loss,
+                clippy::float_arithmetic
+            )] // Only approximate is needed.
+            self.shared as f64 / self.nodes.max(1) as f64
+        }
+
+        /// Recommend the premade variant that fits the shape, given the maximum depth that is
+        /// acceptable to recurse on the call-stack.
+        ///
+        /// That maximum depends on the size of the call-stack and on the node type, because the
+        /// stack frames of the premade functions are larger for larger node types.  Graphs that
+        /// have shared structure, but no cycles, are still recommended a cycle-safe variant,
+        /// because otherwise degenerate DAGs could take exponential time.
+        #[inline]
+        #[must_use]
+        pub fn recommend(
+            &self,
+            max_call_depth: usize,
+        ) -> Variant
+        {
+            let deep = self.max_depth > max_call_depth;
+            let shared = self.cyclic || self.shared > 0;
+            match (shared, deep) {
+                (false, false) => Variant::Basic,
+                (true, false) => Variant::CycleSafe,
+                (false, true) => Variant::DeepSafe,
+                (true, true) => Variant::Robust,
+            }
+        }
+    }
+
+    /// Analyze the shape of the graph reachable from `root`, by traversing it once.
+    ///
+    /// Safe for cyclic, degenerate, and very-deep graphs, by tracking the [`Node::Id`]s of the
+    /// nodes already reached and by using a vector-stack instead of call-stack recursion.
+    #[inline]
+    pub fn analyze<N: Node>(root: N) -> Analysis
+    {
+        let mut analyzer = Analyzer {
+            entries:  HashMap::new(),
+            stack:    Vec::new(),
+            analysis: Analysis {
+                nodes:      0,
+                max_depth:  0,
+                cyclic:     false,
+                max_degree: 0,
+                shared:     0,
+            },
+        };
+        let _: Option<usize> = analyzer.reach(root);
+        analyzer.run()
+    }
+
+    struct Analyzer<N: Node>
+    {
+        entries:  HashMap<N::Id, Reached>,
+        /// The path of nodes, from the root, whose edges are being traversed.
+        stack:    Vec<Frame<N>>,
+        analysis: Analysis,
+    }
+
+    struct Reached
+    {
+        /// Amount of references to the node.
+        references: usize,
+        /// The length of the longest path from the node, once all of its edges were traversed.
+        /// `None` while the node is on the stack.
+        height:     Option<usize>,
+    }
+
+    struct Frame<N: Node>
+    {
+        node:       N,
+        next_index: Option<N::Index>,
+        degree:     usize,
+        height:     usize,
+    }
+
+    impl<N: Node> Frame<N>
+    {
+        fn next_edge(&mut self) -> Option<N>
+        {
+            let index = self.next_index.take()?;
+            let edge = self.node.get_edge(&index)?;
+            self.next_index = increment_index(&index);
+            self.degree = self.degree.saturating_add(1);
+            Some(edge)
+        }
+    }
+
+    impl<N: Node> Analyzer<N>
+    {
+        /// Account for a reference to `node`, and arrange for its edges to be traversed if it was
+        /// not reached before.  Returns the height of `node` if its edges were already
+        /// traversed.
+        fn reach(
+            &mut self,
+            node: N,
+        ) -> Option<usize>
+        {
+            match self.entries.entry(node.id()) {
+                Entry::Occupied(mut occupied) => {
+                    let reached = occupied.get_mut();
+                    reached.references = reached.references.saturating_add(1);
+                    if reached.references == 2 {
+                        self.analysis.shared = self.analysis.shared.saturating_add(1);
+                    }
+                    if reached.height.is_none() {
+                        self.analysis.cyclic = true;
+                    }
+                    reached.height
+                },
+                Entry::Vacant(vacant) => {
+                    let _: &mut Reached =
+                        vacant.insert(Reached { references: 1, height: None });
+                    self.analysis.nodes = self.analysis.nodes.saturating_add(1);
+                    self.stack.push(Frame {
+                        node,
+                        next_index: Some(N::Index::default()),
+                        degree: 0,
+                        height: 0,
+                    });
+                    None
+                },
+            }
+        }
+
+        fn run(mut self) -> Analysis
+        {
+            while let Some(frame) = self.stack.last_mut() {
+                if let Some(edge) = frame.next_edge() {
+                    // When a height is returned, no frame was pushed, and so the last is still
+                    // the frame of the edge's parent.
+                    if let Some(height) = self.reach(edge) {
+                        self.extend_parent(height);
+                    }
+                }
+                else {
+                    self.finish();
+                }
+            }
+            self.analysis
+        }
+
+        /// Done with the last frame, so record its results and give its height to its parent.
+        fn finish(&mut self)
+        {
+            if let Some(frame) = self.stack.pop() {
+                self.analysis.max_degree = self.analysis.max_degree.max(frame.degree);
+                if let Some(reached) = self.entries.get_mut(&frame.node.id()) {
+                    reached.height = Some(frame.height);
+                }
+                if self.stack.is_empty() {
+                    self.analysis.max_depth = frame.height;
+                }
+                else {
+                    self.extend_parent(frame.height);
+                }
+            }
+        }
+
+        /// Account for a path, through an edge of the last frame, to a node with `height`.
+        fn extend_parent(
+            &mut self,
+            height: usize,
+        )
+        {
+            if let Some(parent) = self.stack.last_mut() {
+                parent.height = parent.height.max(height.saturating_add(1));
+            }
+        }
+    }
+}
+
+
+mod checked
+{
+    use crate::{
+        generic::increment_index,
+        Cmp,
+        Node,
+    };
+
+    /// Wrapper of any node type that checks, while a comparison runs, some rules of [`Node`]
+    /// that the algorithm relies on, and that gives a violation as the result of comparison
+    /// instead of silently giving a wrong result.
+    ///
+    /// Checks that [`Node::get_edge`] gives nodes with the same ID when called again for the
+    /// same index, by calling it twice for every index, and that [`Node::id`] gives the same ID
+    /// for a node as when it was wrapped.  The ID given by the wrapper is always the one from
+    /// when it was wrapped, so that the algorithm itself is not misled by unstable IDs.
+    ///
+    /// The rules are checked for both counterparts whenever they are compared, before their
+    /// edges are gotten by the algorithm, so that a violation is given as the result of that
+    /// comparison.  Counterparts that have the same ID are not compared and their edges are not
+    /// descended, and so their violations do not affect the result and are not detected.
+    ///
+    /// Intended for debugging, because of the extra calls.
+    #[derive(Clone, Debug)]
+    pub struct Checked<N: Node>
+    {
+        /// The wrapped node.
+        node: N,
+        /// The ID of the node when it was wrapped.
+        id:   N::Id,
+    }
+
+    /// A violation of a rule of [`Node`] that was detected by [`Checked`].
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    pub enum ContractViolation
+    {
+        /// [`Node::id`] gave a different ID than when the node was wrapped.
+        UnstableId,
+        /// [`Node::get_edge`] gave different results when called again with the same index,
+        /// i.e. `None` and `Some`, or nodes with different IDs.
+        NondeterministicEdge,
+    }
+
+    /// [`Node::Cmp`] type of [`Checked`], which is either the result of comparison of the
+    /// wrapped nodes or a violation that was detected, which is inequivalent so that the
+    /// algorithm stops at it.
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    #[allow(clippy::exhaustive_enums)]
+    pub enum CheckedCmp<C>
+    {
+        /// The result of comparison of the wrapped nodes.
+        Cmp(C),
+        /// A violation was detected.
+        Violation(ContractViolation),
+    }
+
+    impl<N: Node> Checked<N>
+    {
+        /// Wrap `node`, recording its ID.
+        #[inline]
+        pub fn new(node: N) -> Self
+        {
+            Self { id: node.id(), node }
+        }
+
+        /// The wrapped node.
+        #[inline]
+        #[must_use]
+        pub fn node(&self) -> &N
+        {
+            &self.node
+        }
+
+        /// Unwrap the node.
+        #[inline]
+        #[must_use]
+        pub fn into_inner(self) -> N
+        {
+            self.node
+        }
+
+        /// The violation that is detected for `self`, if any, by checking its ID and by getting
+        /// each of its edges twice.
+        fn violation(&self) -> Option<ContractViolation>
+        {
+            if self.node.id() != self.id {
+                return Some(ContractViolation::UnstableId);
+            }
+            let mut next_index = Some(N::Index::default());
+            while let Some(index) = next_index {
+                match (self.node.get_edge(&index), self.node.get_edge(&index)) {
+                    (None, None) => break,
+                    (Some(edge), Some(again)) if edge.id() == again.id() => {
+                        next_index = increment_index(&index);
+                    },
+                    _ => return Some(ContractViolation::NondeterministicEdge),
+                }
+            }
+            None
+        }
+    }
+
+    impl<C> CheckedCmp<C>
+    {
+        /// Convert to a [`Result`] that is `Err` when a violation was detected.
+        ///
+        /// # Errors
+        /// If a violation was detected.
+        #[inline]
+        pub fn into_result(self) -> Result<C, ContractViolation>
+        {
+            match self {
+                Self::Cmp(cmp) => Ok(cmp),
+                Self::Violation(violation) => Err(violation),
+            }
+        }
+    }
+
+    impl<C: Cmp> Cmp for CheckedCmp<C>
+    {
+        #[inline]
+        fn new_equiv() -> Self
+        {
+            Self::Cmp(C::new_equiv())
+        }
+
+        #[inline]
+        fn is_equiv(&self) -> bool
+        {
+            match self {
+                Self::Cmp(cmp) => cmp.is_equiv(),
+                Self::Violation(_) => false,
+            }
+        }
+
+        #[inline]
+        fn from_ord(ord: core::cmp::Ordering) -> Self
+        {
+            Self::Cmp(C::from_ord(ord))
+        }
+    }
+
+    impl<N: Node> Node for Checked<N>
+    {
+        type Cmp = CheckedCmp<N::Cmp>;
+        type Id = N::Id;
+        type Index = N::Index;
+
+        /// The ID from when the node was wrapped.
+        #[inline]
+        fn id(&self) -> Self::Id
+        {
+            self.id.clone()
+        }
+
+        /// Wraps the edge of the wrapped node.
+        #[inline]
+        fn get_edge(
+            &self,
+            index: &Self::Index,
+        ) -> Option<Self>
+        {
+            self.node.get_edge(index).map(Self::new)
+        }
+
+        /// Gives the violation detected for either node, else the result of comparison of the
+        /// wrapped nodes.  This is done before the algorithm gets the edges of the nodes, so
+        /// that a violation of those is not missed when the edges are not compared.
+        #[inline]
+        fn equiv_modulo_edges(
+            &self,
+            other: &Self,
+        ) -> Self::Cmp
+        {
+            match self.violation().or_else(|| other.violation()) {
+                Some(violation) => CheckedCmp::Violation(violation),
+                None => CheckedCmp::Cmp(self.node.equiv_modulo_edges(&other.node)),
+            }
+        }
+    }
+}
+
+
 mod non_advancing_iterator
 {
     /// An `Iterator` that can repeatedly yield the same next item without advancing.
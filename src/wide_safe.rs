@@ -167,7 +167,19 @@ pub mod recursion
             #[inline]
             fn default() -> Self
             {
-                Self(LazyVecStack::with_capacity(P::INITIAL_CAPACITY))
+                Self::with_capacity(P::INITIAL_CAPACITY)
+            }
+        }
+
+        impl<P: Params> RecurStack<P>
+        {
+            /// Create a new instance with the given `capacity`, instead of
+            /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY), e.g. chosen at run-time.
+            #[inline]
+            #[must_use]
+            pub fn with_capacity(capacity: usize) -> Self
+            {
+                Self(LazyVecStack::with_capacity(capacity))
             }
         }
 
@@ -215,6 +227,223 @@ pub mod recursion
                 self.0.clear();
                 self
             }
+
+            #[inline]
+            fn clear(&mut self)
+            {
+                self.0.clear();
+            }
+        }
+    }
+
+    pub mod hybrid
+    {
+        //! Use the call-stack for the recursion up to a limited depth, and beyond that use
+        //! `LazyVecStack` like [`RecurStack`](super::stack::RecurStack).
+        //!
+        //! This avoids the overhead of the vector-stack for inputs that are shallow enough, while
+        //! still being able to handle very-deep inputs.  The order of traversal is the same as
+        //! that of [`CallStack`] (and of `RecurStack`), and so the results of comparing by
+        //! [`Ordering`] are the same.
+
+        use {
+            crate::{
+                basic::recursion::callstack::CallStack,
+                generic::equiv::{
+                    self,
+                    CounterpartsResult,
+                    EdgesIter,
+                    Equiv,
+                    RecurMode,
+                },
+                utils::{
+                    LazierIterator as _,
+                    LazyVecStack,
+                },
+                Cmp,
+                Node,
+            },
+            core::cmp::Ordering,
+        };
+
+        /// Generic parameters of [`Hybrid`] and its operations.
+        pub trait Params
+        {
+            /// Maximum depth of recursion that is done on the call-stack.  Descending beyond this
+            /// depth is done with a vector-stack instead.
+            ///
+            /// The default is conservative, to be safe with the small call-stacks of spawned
+            /// threads even in unoptimized builds.  An `impl` of [`Params`] may be made with a
+            /// different value - either smaller (e.g. when [`Node`] methods use much of the
+            /// call-stack) or larger (e.g. when the call-stack is known to be large).
+            const MAX_CALL_DEPTH: usize = 2_usize.pow(8);
+            /// Amount of elements that the vector-stack can contain initially before
+            /// reallocating.  Like
+            /// [`stack::Params::INITIAL_CAPACITY`](super::stack::Params::INITIAL_CAPACITY).
+            const INITIAL_CAPACITY: usize = 2_usize.pow(4);
+            /// Type of node that is saved on the vector-stack.  Must be the same as used with the
+            /// corresponding [`equiv::Params`].
+            type Node: Node;
+        }
+
+        /// Recursion on the call-stack up to a limited depth, beyond which lazily-generated pairs
+        /// of nodes are saved on a vector-stack.  Specifies use of this.
+        ///
+        /// Does depth-first preorder traversals.  The continuations beyond the maximum depth are
+        /// completely done before returning to the shallower call-stack recursions, which keeps
+        /// the same order as [`CallStack`].
+        pub struct Hybrid<P: Params>
+        {
+            /// Current depth of recursion on the call-stack.  Exceeds the maximum only while the
+            /// vector-stack is being used.
+            depth:          usize,
+            /// Maximum depth of recursion on the call-stack.
+            max_call_depth: usize,
+            /// Saved continuations beyond the maximum depth.
+            stack:          LazyVecStack<EdgesIter<P::Node>>,
+        }
+
+        impl<P: Params> Default for Hybrid<P>
+        {
+            /// Create a new instance with maximum call-stack depth
+            /// [`P::MAX_CALL_DEPTH`](Params::MAX_CALL_DEPTH) and capacity
+            /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY).
+            #[inline]
+            fn default() -> Self
+            {
+                Self::with_capacity(P::INITIAL_CAPACITY)
+            }
+        }
+
+        impl<P: Params> Hybrid<P>
+        {
+            /// Create a new instance with the given `capacity` of the vector-stack, instead of
+            /// [`P::INITIAL_CAPACITY`](Params::INITIAL_CAPACITY), e.g. chosen at run-time.
+            #[inline]
+            #[must_use]
+            pub fn with_capacity(capacity: usize) -> Self
+            {
+                Self {
+                    depth:          0,
+                    max_call_depth: P::MAX_CALL_DEPTH,
+                    stack:          LazyVecStack::with_capacity(capacity),
+                }
+            }
+
+            /// Use the given maximum depth of recursion on the call-stack, instead of
+            /// [`P::MAX_CALL_DEPTH`](Params::MAX_CALL_DEPTH), e.g. chosen at run-time.
+            #[inline]
+            #[must_use]
+            pub fn with_max_call_depth(
+                self,
+                max_call_depth: usize,
+            ) -> Self
+            {
+                Self { max_call_depth, ..self }
+            }
+        }
+
+        /// Enables the call-stack to be used for the precheck and the hybrid for the interleave,
+        /// if desired.
+        impl<P: Params> From<CallStack> for Hybrid<P>
+        {
+            #[inline]
+            fn from(_: CallStack) -> Self
+            {
+                Self::default()
+            }
+        }
+
+        /// Enables [`Hybrid`] to be used with the algorithm.
+        impl<E, V> RecurMode<E> for Hybrid<V>
+        where
+            E: equiv::Params<RecurMode = Self>,
+            V: Params<Node = E::Node>,
+        {
+            type Error = E::Error;
+
+            #[inline]
+            fn recur(
+                it: &mut Equiv<E>,
+                edges_iter: EdgesIter<E::Node>,
+            ) -> Result<<E::Node as Node>::Cmp, Self::Error>
+            {
+                let depth = it.recur_mode.depth;
+
+                match depth.cmp(&it.recur_mode.max_call_depth) {
+                    // Shallower than the maximum, so recur on the call-stack, like `CallStack`.
+                    Ordering::Less => {
+                        it.recur_mode.depth = depth.saturating_add(1);
+                        let mut result = Ok(Cmp::new_equiv());
+                        for next in edges_iter {
+                            match next {
+                                Ok([a, b]) => match it.equiv_main(a, b) {
+                                    Ok(cmp) if cmp.is_equiv() => (),
+                                    r => {
+                                        result = r;
+                                        break;
+                                    },
+                                },
+                                Err(cmp_amount_edges) => {
+                                    result = Ok(cmp_amount_edges);
+                                    break;
+                                },
+                            }
+                        }
+                        it.recur_mode.depth = depth;
+                        result
+                    },
+                    // Already using the vector-stack, so save for later, like `RecurStack`.
+                    Ordering::Greater => {
+                        it.recur_mode.stack.extend(edges_iter);
+                        Ok(Cmp::new_equiv())
+                    },
+                    // At the maximum, so use the vector-stack for all the descendents, and finish
+                    // them before returning, to keep the same order as the call-stack.
+                    Ordering::Equal => {
+                        it.recur_mode.depth = depth.saturating_add(1);
+                        it.recur_mode.stack.extend(edges_iter);
+                        let result = loop {
+                            match it.recur_mode.stack.next() {
+                                Some(Ok([a, b])) => match it.equiv_main(a, b) {
+                                    Ok(cmp) if cmp.is_equiv() => (),
+                                    r => break r,
+                                },
+                                Some(Err(cmp_amount_edges)) => break Ok(cmp_amount_edges),
+                                None => break Ok(Cmp::new_equiv()),
+                            }
+                        };
+                        it.recur_mode.depth = depth;
+                        result
+                    },
+                }
+            }
+
+            /// All continuations are finished by [`Self::recur`], so there never are any for
+            /// later.
+            #[inline]
+            fn next(&mut self) -> Option<CounterpartsResult<E::Node>>
+            {
+                None
+            }
+
+            /// An aborted invocation might have left some elements on the vector-stack, so we
+            /// must reset before reusing it.
+            #[inline]
+            fn reset(mut self) -> Self
+            {
+                self.depth = 0;
+                self.stack.clear();
+                self
+            }
+
+            /// Keeps the maximum depth, which might have been given instead of the default.
+            #[inline]
+            fn clear(&mut self)
+            {
+                self.depth = 0;
+                self.stack.clear();
+            }
         }
     }
 }
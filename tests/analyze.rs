@@ -0,0 +1,87 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::utils::{
+        analyze,
+        Analysis,
+        Variant,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+fn analyze_arena(nodes: Vec<Vec<usize>>) -> Analysis
+{
+    analyze(My(&Arena::from_edges(nodes), 0))
+}
+
+/// Compare the fields, because `Analysis` is non-exhaustive and so cannot be constructed here.
+fn shape(analysis: &Analysis) -> (usize, usize, bool, usize, usize)
+{
+    (analysis.nodes, analysis.max_depth, analysis.cyclic, analysis.max_degree, analysis.shared)
+}
+
+
+#[test]
+fn leaf()
+{
+    let analysis = analyze_arena(vec![vec![]]);
+    assert_eq!(shape(&analysis), (1, 0, false, 0, 0));
+    assert_eq!(analysis.shared_fraction(), 0.0);
+    assert_eq!(analysis.recommend(0), Variant::Basic);
+}
+
+#[test]
+fn tree()
+{
+    let analysis = analyze_arena(vec![vec![1, 2, 3], vec![4], vec![], vec![], vec![5], vec![]]);
+    assert_eq!(shape(&analysis), (6, 3, false, 3, 0));
+    assert_eq!(analysis.recommend(3), Variant::Basic);
+    assert_eq!(analysis.recommend(2), Variant::DeepSafe);
+}
+
+#[test]
+fn dag()
+{
+    // The longest path is not the first one traversed.
+    let analysis = analyze_arena(vec![vec![3, 1], vec![2], vec![3], vec![4], vec![]]);
+    assert_eq!(shape(&analysis), (5, 4, false, 2, 1));
+    assert_eq!(analysis.shared_fraction(), 0.2);
+    assert_eq!(analysis.recommend(10), Variant::CycleSafe);
+    assert_eq!(analysis.recommend(3), Variant::Robust);
+}
+
+#[test]
+fn cyclic()
+{
+    let analysis = analyze_arena(vec![vec![1], vec![2], vec![0]]);
+    assert_eq!(shape(&analysis), (3, 2, true, 1, 1));
+    assert_eq!(analysis.recommend(10), Variant::CycleSafe);
+
+    let analysis = analyze_arena(vec![vec![0, 0]]);
+    assert_eq!(shape(&analysis), (1, 0, true, 2, 1));
+    assert_eq!(analysis.shared_fraction(), 1.0);
+}
+
+#[test]
+fn degenerate()
+{
+    const DEPTH: usize = 100;
+
+    let mut nodes: Vec<_> = (0 .. DEPTH).map(|i| vec![i + 1, i + 1]).collect();
+    nodes.push(vec![]);
+    let analysis = analyze_arena(nodes);
+    assert_eq!(shape(&analysis), (DEPTH + 1, DEPTH, false, 2, DEPTH));
+}
+
+#[test]
+fn long()
+{
+    const LENGTH: usize = 1_000_000;
+
+    let analysis = analyze_arena((0 .. LENGTH).map(|i| vec![i + 1]).chain([vec![0]]).collect());
+    assert_eq!(shape(&analysis), (LENGTH + 1, LENGTH, true, 1, 1));
+    assert_eq!(analysis.recommend(1000), Variant::Robust);
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        asynchronous::{
+            equiv,
+            AsyncEquiv,
+            AsyncNode,
+        },
+        basic::modes::{
+            limited::{
+                LimitReached,
+                Limited,
+            },
+            unlimited::Unlimited,
+        },
+        utils::Order,
+    },
+    std::{
+        cmp::Ordering,
+        future::Future,
+        pin::Pin,
+        rc::Rc,
+        sync::Arc,
+        task::{
+            Context,
+            Poll,
+            Wake,
+            Waker,
+        },
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+
+/// Minimal executor, which shows that no runtime is needed.
+fn block_on<F: Future>(future: F) -> F::Output
+{
+    struct Noop;
+
+    impl Wake for Noop
+    {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(Noop));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            break output;
+        }
+    }
+}
+
+/// Gives its value only when polled the second time, like a fetch that must be waited for.
+struct Delayed<T>
+{
+    value:  Option<T>,
+    polled: bool,
+}
+
+impl<T> Delayed<T>
+{
+    fn new(value: T) -> Self
+    {
+        Delayed { value: Some(value), polled: false }
+    }
+}
+
+impl<T: Unpin> Future for Delayed<T>
+{
+    type Output = T;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<T>
+    {
+        if self.polled {
+            Poll::Ready(self.value.take().unwrap())
+        }
+        else {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+
+/// Owns a handle to the arena, because the futures cannot borrow the node.
+#[derive(Clone)]
+struct My(Rc<Arena>, usize);
+
+impl AsyncNode for My
+{
+    type Cmp = Ordering;
+    type CmpFuture = Delayed<Ordering>;
+    type EdgeFuture = Delayed<Option<Self>>;
+    type Id = (*const Arena, usize);
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        (Rc::as_ptr(&self.0), self.1)
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Self::EdgeFuture
+    {
+        let edge = self.0.edges(self.1).get(*index).map(|&i| My(Rc::clone(&self.0), i));
+        Delayed::new(edge)
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::CmpFuture
+    {
+        Delayed::new(self.0.value(self.1).cmp(other.0.value(other.1)))
+    }
+}
+
+fn root(nodes: Vec<(i32, Vec<usize>)>) -> My
+{
+    My(Rc::new(Arena::new(nodes)), 0)
+}
+
+fn list(len: usize) -> My
+{
+    My(Rc::new(Arena::list(len, 1)), 0)
+}
+
+fn degenerate_cyclic(len: usize) -> My
+{
+    My(Rc::new(Arena::degenerate_cyclic(len, 1)), 0)
+}
+
+
+#[test]
+fn trees()
+{
+    let a = || root(vec![(1, vec![1, 2]), (2, vec![]), (3, vec![])]);
+    assert_eq!(block_on(equiv(a(), a())), Ordering::Equal);
+
+    let b = root(vec![(1, vec![1, 2]), (2, vec![]), (4, vec![])]);
+    assert_eq!(block_on(equiv(a(), b.clone())), Ordering::Less);
+    assert_eq!(block_on(equiv(b, a())), Ordering::Greater);
+}
+
+#[test]
+fn amount_edges()
+{
+    let a = || root(vec![(1, vec![1]), (2, vec![])]);
+    let b = || root(vec![(1, vec![1, 1]), (2, vec![])]);
+    assert_eq!(block_on(equiv(a(), b())), Ordering::Less);
+    assert_eq!(block_on(equiv(b(), a())), Ordering::Greater);
+
+    let c = root(vec![(1, vec![1]), (2, vec![2]), (3, vec![])]);
+    assert_eq!(block_on(equiv(a(), c)), Ordering::Less);
+}
+
+#[test]
+fn same()
+{
+    let a = list(3);
+    assert_eq!(block_on(equiv(a.clone(), a)), Ordering::Equal);
+}
+
+#[test]
+fn cyclic()
+{
+    assert_eq!(block_on(equiv(degenerate_cyclic(100), degenerate_cyclic(100))), Ordering::Equal);
+    assert_eq!(block_on(equiv(degenerate_cyclic(100), degenerate_cyclic(3))), Ordering::Equal);
+
+    let other = root(vec![(1, vec![1, 1]), (1, vec![2, 2]), (2, vec![0, 0])]);
+    assert_eq!(block_on(equiv(degenerate_cyclic(3), other)), Ordering::Less);
+}
+
+#[test]
+fn deep()
+{
+    const DEPTH: usize = 100_000;
+
+    assert_eq!(block_on(equiv(list(DEPTH), list(DEPTH))), Ordering::Equal);
+    assert_eq!(block_on(equiv(list(DEPTH), list(DEPTH + 1))), Ordering::Less);
+
+    for order in [Order::DepthFirst, Order::BreadthFirst] {
+        let mut e = AsyncEquiv::new(Unlimited, order);
+        assert_eq!(block_on(e.equiv(list(DEPTH), list(DEPTH))), Ok(Ordering::Equal));
+    }
+}
+
+#[test]
+fn limited()
+{
+    let mut e = AsyncEquiv::new(Limited(10_u16), Order::DepthFirst);
+    assert_eq!(block_on(e.equiv(list(5), list(5))), Ok(Ordering::Equal));
+    // The limit applies to the total across invocations.
+    assert_eq!(block_on(e.equiv(list(5), list(5))), Ok(Ordering::Equal));
+    assert_eq!(block_on(e.equiv(list(1), list(1))), Err(LimitReached));
+}
+
+#[test]
+fn reuse()
+{
+    let mut e = AsyncEquiv::new(Unlimited, Order::BreadthFirst);
+    let a = || root(vec![(1, vec![1, 2]), (2, vec![]), (3, vec![])]);
+    let b = root(vec![(1, vec![1, 2]), (0, vec![]), (3, vec![])]);
+    assert_eq!(block_on(e.equiv(a(), b)), Ok(Ordering::Greater));
+    assert_eq!(block_on(e.equiv(a(), a())), Ok(Ordering::Equal));
+}
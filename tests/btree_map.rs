@@ -0,0 +1,145 @@
+//! Use of the premade items that only require "alloc", which can be run without "std", e.g. by
+//! `cargo test --no-default-features --features alloc`.
+
+#![cfg(feature = "alloc")]
+
+use {
+    core::{
+        convert::Infallible,
+        marker::PhantomData,
+    },
+    graph_safe_compare::{
+        cycle_safe,
+        cycle_safe::modes::interleave::{
+            self,
+            random::default,
+            Interleave,
+        },
+        deep_safe::recursion::{
+            self,
+            queue::RecurQueue,
+        },
+        generic::{
+            equiv::{
+                self,
+                Equiv,
+            },
+            equiv_classes::premade::btree_map,
+        },
+        robust,
+        Node,
+    },
+};
+
+
+/// Nodes are elements of a slice, and edges are indexes of other elements.  The ID type is
+/// `Ord`, as is required by the premade functions without "std".
+#[derive(Copy, Clone)]
+struct My<'l>(&'l [(char, Vec<usize>)], usize);
+
+impl<'l> Node for My<'l>
+{
+    type Cmp = bool;
+    type Id = (*const (char, Vec<usize>), usize);
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        (self.0.as_ptr(), self.1)
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        self.0[self.1].1.get(*index).map(|&i| My(self.0, i))
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.0[self.1].0 == other.0[other.1].0
+    }
+}
+
+/// A cycle of nodes that has the given values, and a tail that is a long list that leads into
+/// the cycle.
+fn lasso(
+    values: &str,
+    tail_len: usize,
+) -> Vec<(char, Vec<usize>)>
+{
+    let cycle_len = values.len();
+    let mut nodes: Vec<_> = values
+        .chars()
+        .enumerate()
+        .map(|(i, v)| (v, vec![if i + 1 < cycle_len { i + 1 } else { 0 }]))
+        .collect();
+    nodes
+        .extend((0 .. tail_len).map(|i| ('t', vec![if i == 0 { 0 } else { cycle_len + i - 1 }])));
+    nodes
+}
+
+fn root(nodes: &[(char, Vec<usize>)]) -> My<'_>
+{
+    My(nodes, nodes.len() - 1)
+}
+
+
+#[test]
+fn premade()
+{
+    let (a, b, c) = (lasso("ab", 1000), lasso("abab", 1000), lasso("aba", 1000));
+
+    assert!(cycle_safe::equiv(root(&a), root(&b)));
+    assert!(!cycle_safe::equiv(root(&a), root(&c)));
+    assert!(cycle_safe::precheck_equiv(root(&a), root(&b)));
+    assert!(!cycle_safe::precheck_equiv(root(&a), root(&c)));
+
+    let (a, b, c) = (lasso("ab", 200_000), lasso("abab", 200_000), lasso("aba", 200_000));
+
+    assert!(robust::equiv(root(&a), root(&b)));
+    assert!(!robust::equiv(root(&a), root(&c)));
+    assert!(robust::precheck_equiv(root(&a), root(&b)));
+    assert!(!robust::precheck_equiv(root(&a), root(&c)));
+}
+
+#[test]
+fn generic()
+{
+    struct Args<'l>(PhantomData<&'l ()>);
+
+    impl<'l> equiv::Params for Args<'l>
+    {
+        type DescendMode = Interleave<Self>;
+        type Error = Infallible;
+        type Node = My<'l>;
+        type RecurMode = RecurQueue<Self>;
+    }
+
+    impl<'l> interleave::Params for Args<'l>
+    {
+        type Node = My<'l>;
+        type RNG = default::RandomNumberGenerator;
+        type Table = btree_map::Table<Self>;
+    }
+
+    impl<'l> btree_map::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    impl<'l> recursion::queue::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    let (a, b, c) = (lasso("xyz", 10_000), lasso("xyzxyz", 10), lasso("xyzxy", 10_000));
+
+    assert_eq!(Equiv::<Args<'_>>::default().equiv(root(&a), root(&b)), Ok(false));
+    assert_eq!(Equiv::<Args<'_>>::default().equiv(My(&a, 0), My(&b, 3)), Ok(true));
+    assert_eq!(Equiv::<Args<'_>>::default().equiv(My(&a, 0), My(&c, 0)), Ok(false));
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        basic,
+        deep_safe,
+        robust,
+        utils::{
+            Checked,
+            CheckedCmp,
+            ContractViolation,
+        },
+        Node,
+    },
+    std::cell::Cell,
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// Which rule a node type breaks.
+#[derive(Copy, Clone, PartialEq)]
+enum Bug
+{
+    None,
+    UnstableId,
+    NondeterministicEdge,
+}
+
+/// An arena whose nodes break a rule.
+struct BuggyArena
+{
+    arena:   Arena,
+    bug:     Bug,
+    counter: Cell<usize>,
+}
+
+/// Wraps the correct node type, to break the rule of its arena.
+#[derive(Copy, Clone)]
+struct Buggy<'l>
+{
+    arena: &'l BuggyArena,
+    node:  My<'l>,
+}
+
+impl<'l> Buggy<'l>
+{
+    fn root(arena: &'l BuggyArena) -> Self
+    {
+        Buggy { arena, node: My(&arena.arena, 0) }
+    }
+
+    /// Gives a different value each time.
+    fn count(&self) -> usize
+    {
+        let count = self.arena.counter.get();
+        self.arena.counter.set(count + 1);
+        count
+    }
+}
+
+impl<'l> Node for Buggy<'l>
+{
+    type Cmp = bool;
+    type Id = <My<'l> as Node>::Id;
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        match self.arena.bug {
+            Bug::UnstableId => (self.node.id().0, self.count()),
+            _ => self.node.id(),
+        }
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        let edge = self.node.get_edge(index).map(|node| Buggy { node, ..*self });
+        match self.arena.bug {
+            // Only the second call, of the two for each index, is wrong.
+            Bug::NondeterministicEdge if self.count() % 2 == 1 => None,
+            _ => edge,
+        }
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.node.equiv_modulo_edges(&other.node)
+    }
+}
+
+/// A cycle of `len` nodes, with a last value that varies.
+fn cycle(
+    len: usize,
+    last_val: i32,
+    bug: Bug,
+) -> BuggyArena
+{
+    let mut nodes: Vec<_> = (0 .. len).map(|i| (0, vec![(i + 1) % len])).collect();
+    nodes[len - 1].0 = last_val;
+    BuggyArena { arena: Arena::new(nodes), bug, counter: Cell::new(0) }
+}
+
+fn checked(arena: &BuggyArena) -> Checked<Buggy<'_>>
+{
+    Checked::new(Buggy::root(arena))
+}
+
+
+#[test]
+fn valid()
+{
+    let (a, b, c) = (cycle(3, 1, Bug::None), cycle(3, 1, Bug::None), cycle(3, 2, Bug::None));
+    assert_eq!(robust::equiv(checked(&a), checked(&b)), CheckedCmp::Cmp(true));
+    assert_eq!(robust::equiv(checked(&a), checked(&c)).into_result(), Ok(false));
+
+    let checked = checked(&a);
+    assert_eq!(checked.node().node.1, 0);
+    assert_eq!(checked.into_inner().node.1, 0);
+}
+
+#[test]
+fn unstable_id()
+{
+    let (a, b) = (cycle(3, 1, Bug::UnstableId), cycle(3, 1, Bug::None));
+    assert_eq!(
+        robust::equiv(checked(&a), checked(&b)).into_result(),
+        Err(ContractViolation::UnstableId)
+    );
+    assert_eq!(
+        robust::equiv(checked(&b), checked(&a)).into_result(),
+        Err(ContractViolation::UnstableId)
+    );
+}
+
+#[test]
+fn nondeterministic_edge()
+{
+    let (a, b) = (cycle(3, 1, Bug::NondeterministicEdge), cycle(3, 1, Bug::None));
+    // Without the wrapper, a wrong result is given.
+    assert!(!robust::equiv(Buggy::root(&a), Buggy::root(&b)));
+
+    let expected = CheckedCmp::Violation(ContractViolation::NondeterministicEdge);
+    assert_eq!(robust::equiv(checked(&a), checked(&b)), expected);
+    assert_eq!(deep_safe::equiv(checked(&b), checked(&a)), expected);
+
+    let (a, b) = (cycle(3, 1, Bug::NondeterministicEdge), cycle(3, 1, Bug::None));
+    assert_eq!(basic::limited_equiv(10_u8, checked(&a), checked(&b)), Ok(expected));
+}
+
+/// The edges of both roots are the same node, which is compared with itself and so is skipped,
+/// but the nondeterminism of getting it is still detected.
+#[test]
+fn nondeterministic_edge_to_self()
+{
+    let a = BuggyArena {
+        arena:   Arena::new(vec![(0, vec![2]), (0, vec![2]), (1, vec![])]),
+        bug:     Bug::NondeterministicEdge,
+        counter: Cell::new(0),
+    };
+    let root = |index| Checked::new(Buggy { arena: &a, node: My(&a.arena, index) });
+
+    let expected = CheckedCmp::Violation(ContractViolation::NondeterministicEdge);
+    assert_eq!(robust::equiv(root(0), root(1)), expected);
+    assert_eq!(basic::equiv(root(0), root(1)), expected);
+}
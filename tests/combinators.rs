@@ -0,0 +1,274 @@
+#![cfg(feature = "std")]
+#![cfg_attr(feature = "anticipate", feature(never_type))]
+
+use {
+    cfg_if::cfg_if,
+    graph_safe_compare::{
+        basic::modes::{
+            limited::{
+                LimitReached,
+                Limited,
+            },
+            unlimited::Unlimited,
+        },
+        cycle_safe::modes::interleave::{
+            self,
+            random,
+            Interleave,
+        },
+        generic::{
+            combinators::{
+                self,
+                Both,
+                Either,
+                Map,
+            },
+            equiv::{
+                self,
+                Composable,
+                Equiv,
+            },
+            equiv_classes::premade::hash_map,
+        },
+        robust,
+        wide_safe::recursion::{
+            self,
+            stack::RecurStack,
+        },
+    },
+    std::{
+        convert::Infallible,
+        marker::PhantomData,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// A list of `len` nodes, with a last value that varies.
+fn list(
+    len: usize,
+    last_val: i32,
+) -> Arena
+{
+    let mut nodes: Vec<_> = (0 .. len).map(|i| (0, vec![i + 1])).collect();
+    nodes.push((last_val, vec![]));
+    Arena::new(nodes)
+}
+
+/// A cycle of `len` nodes.
+fn cycle(len: usize) -> Arena
+{
+    Arena::new((0 .. len).map(|i| (0, vec![(i + 1) % len])).collect())
+}
+
+cfg_if! {
+    if #[cfg(feature = "anticipate")] {
+        /// The error type of the recursion modes.
+        type RecurInfallible = !;
+    }
+    else {
+        /// The error type of the recursion modes.
+        type RecurInfallible = Infallible;
+    }
+}
+
+/// Never descends edges.
+struct Shallow;
+
+impl<'l> Composable<My<'l>> for Shallow
+{
+    type Error = Infallible;
+
+    fn do_edges(
+        &mut self,
+        _a: &My<'l>,
+        _b: &My<'l>,
+    ) -> Result<bool, Self::Error>
+    {
+        Ok(false)
+    }
+
+    fn do_traverse(&mut self) -> Result<bool, Self::Error>
+    {
+        Ok(true)
+    }
+}
+
+/// Like `Limited` but with a different error type.
+struct Countdown(u32);
+
+#[derive(Debug, PartialEq)]
+struct Exhausted;
+
+impl<'l> Composable<My<'l>> for Countdown
+{
+    type Error = Exhausted;
+
+    fn do_edges(
+        &mut self,
+        _a: &My<'l>,
+        _b: &My<'l>,
+    ) -> Result<bool, Self::Error>
+    {
+        Ok(true)
+    }
+
+    fn do_traverse(&mut self) -> Result<bool, Self::Error>
+    {
+        self.0 = self.0.checked_sub(1).ok_or(Exhausted)?;
+        Ok(true)
+    }
+}
+
+/// Mapped from other errors.
+#[derive(Debug, PartialEq)]
+struct Which(&'static str);
+
+impl From<RecurInfallible> for Exhausted
+{
+    fn from(_: RecurInfallible) -> Self
+    {
+        unreachable!()
+    }
+}
+
+impl From<RecurInfallible> for Which
+{
+    fn from(_: RecurInfallible) -> Self
+    {
+        unreachable!()
+    }
+}
+
+struct Args<'l, M>(PhantomData<(&'l (), M)>);
+
+impl<'l, M> equiv::Params for Args<'l, M>
+where
+    M: Composable<My<'l>>,
+    M::Error: From<RecurInfallible>,
+{
+    type DescendMode = M;
+    type Error = M::Error;
+    type Node = My<'l>;
+    type RecurMode = RecurStack<Self>;
+}
+
+impl<'l, M> interleave::Params for Args<'l, M>
+{
+    type Node = My<'l>;
+    type RNG = random::default::RandomNumberGenerator;
+    type Table = hash_map::Table<Self>;
+}
+
+impl<'l, M> hash_map::Params for Args<'l, M>
+{
+    type Node = My<'l>;
+}
+
+impl<'l, M> recursion::stack::Params for Args<'l, M>
+{
+    type Node = My<'l>;
+}
+
+type Error<A, B> = combinators::Error<A, B>;
+
+fn equiv<'l, M>(
+    mode: M,
+    a: &'l Arena,
+    b: &'l Arena,
+) -> Result<bool, M::Error>
+where
+    M: Composable<My<'l>>,
+    M::Error: From<RecurInfallible>,
+{
+    Equiv::<Args<'l, M>>::new(mode).equiv(My(a, 0), My(b, 0))
+}
+
+
+#[test]
+fn robust_limited()
+{
+    let (a, b, c) = (list(10, 1), list(10, 1), list(10, 2));
+    assert_eq!(robust::limited_equiv(100, My(&a, 0), My(&b, 0)), Ok(true));
+    assert_eq!(robust::limited_equiv(100, My(&a, 0), My(&c, 0)), Ok(false));
+    assert!(robust::limited_equiv(5, My(&a, 0), My(&b, 0)).is_err());
+
+    let (a, b) = (cycle(3), cycle(7));
+    assert_eq!(robust::limited_equiv(1000, My(&a, 0), My(&b, 0)), Ok(true));
+    assert!(robust::limited_equiv(3, My(&a, 0), My(&b, 0)).is_err());
+}
+
+#[test]
+fn both()
+{
+    type Interleaved<'l> = Interleave<Args<'l, ()>>;
+
+    let (a, b) = (cycle(3), cycle(7));
+
+    let mode = Both(Limited(1000_u32), Interleaved::default());
+    assert_eq!(equiv(mode, &a, &b), Ok(true));
+
+    let mode = Both(Limited(3_u32), Interleaved::default());
+    assert_eq!(equiv(mode, &a, &b), Err(Error::First(LimitReached)));
+
+    let mode = Both(Interleaved::default(), Countdown(3));
+    assert_eq!(equiv(mode, &a, &b), Err(Error::Second(Exhausted)));
+
+    // The error of the first is given when both error.
+    let mode = Both(Limited(3_u8), Countdown(3));
+    assert_eq!(equiv(mode, &a, &b), Err(Error::First(LimitReached)));
+
+    // Descends only when both do.
+    let mode = Both(Countdown(1), Shallow);
+    assert_eq!(equiv(mode, &a, &b), Ok(true));
+
+    // Nested.
+    let mode = Both(Limited(1000_u32), Both(Limited(2000_u16), Interleaved::default()));
+    assert_eq!(equiv(mode, &a, &b), Ok(true));
+}
+
+#[test]
+fn either()
+{
+    let (a, b) = (list(10, 1), list(10, 2));
+
+    // Descends because `Unlimited` does, even though `Shallow` does not.
+    let mode = Either(Unlimited, Shallow);
+    assert_eq!(equiv(mode, &a, &b), Ok(false));
+
+    let mode = Either(Shallow, Shallow);
+    assert_eq!(equiv(mode, &a, &b), Ok(true));
+
+    let mode = Either(Shallow, Countdown(5));
+    assert_eq!(equiv(mode, &a, &b), Err(Error::Second(Exhausted)));
+}
+
+#[test]
+fn map()
+{
+    let (a, b) = (list(10, 1), list(10, 1));
+
+    let mode = Map::new(Both(Limited(5_u32), Countdown(100)), |e| match e {
+        Error::First(LimitReached) => Which("limit"),
+        Error::Second(Exhausted) => Which("exhausted"),
+    });
+    assert_eq!(equiv(Both(Unlimited, mode), &a, &b), Err(Error::Second(Which("limit"))));
+
+    let mode = Map::new(Limited(5_u32), |LimitReached| Exhausted);
+    assert_eq!(mode.mode().0, 5);
+    assert_eq!(equiv(mode, &a, &b), Err(Exhausted));
+}
+
+#[test]
+fn reset()
+{
+    type Interleaved<'l> = Interleave<Args<'l, ()>>;
+
+    let (a, b) = (cycle(3), cycle(7));
+    let mut e = Equiv::<Args<'_, Both<Unlimited, Interleaved<'_>>>>::default();
+    assert_eq!(e.equiv(My(&a, 0), My(&b, 0)), Ok(true));
+    e.reset();
+    assert_eq!(e.equiv(My(&b, 0), My(&a, 0)), Ok(true));
+}
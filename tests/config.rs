@@ -0,0 +1,187 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        cycle_safe::modes::interleave::{
+            self,
+            random,
+            Config,
+            Interleave,
+        },
+        generic::{
+            equiv::{
+                self,
+                Equiv,
+            },
+            equiv_classes::premade::arena,
+            precheck_interleave,
+        },
+        robust,
+        wide_safe::recursion::{
+            self,
+            stack::RecurStack,
+        },
+    },
+    std::{
+        convert::Infallible,
+        marker::PhantomData,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+const DEPTH: usize = 30;
+
+/// Each node has two edges to the next, so the unfolding is a tree with `2^DEPTH` leaves, which
+/// is only feasible to compare by detecting the shared structure.
+fn degenerate_dag(last_val: i32) -> Arena
+{
+    let mut nodes: Vec<_> = (0 .. DEPTH).map(|i| (0, vec![i + 1, i + 1])).collect();
+    nodes.push((last_val, vec![]));
+    Arena::new(nodes)
+}
+
+/// A cycle of `len` nodes.
+fn cycle(len: usize) -> Arena
+{
+    let nodes = (0 .. len).map(|i| (0, vec![(i + 1) % len])).collect();
+    Arena::new(nodes)
+}
+
+/// Only a few nodes are traversed before the "slow" phase, and that lasts long.
+fn eager_config() -> Config
+{
+    let mut config = Config::default();
+    config.precheck_limit = 2;
+    config.fast_limit_max = 0;
+    config.slow_limit = 1000;
+    config.table_capacity = 8;
+    config
+}
+
+
+#[test]
+fn default_is_params()
+{
+    struct Args;
+
+    impl interleave::Params for Args
+    {
+        type Node = My<'static>;
+        type RNG = random::default::RandomNumberGenerator;
+        type Table = arena::Table<Self>;
+    }
+
+    impl arena::Params for Args
+    {
+        type Node = My<'static>;
+    }
+
+    assert_eq!(Config::from_params::<Args>(), Config::default());
+}
+
+#[test]
+fn premade()
+{
+    let (a, b, c) = (degenerate_dag(1), degenerate_dag(1), degenerate_dag(2));
+    let config = eager_config();
+
+    assert!(robust::equiv_with(&config, My(&a, 0), My(&b, 0)));
+    assert!(!robust::equiv_with(&config, My(&a, 0), My(&c, 0)));
+    assert!(robust::precheck_equiv_with(&config, My(&a, 0), My(&b, 0)));
+    assert!(!robust::precheck_equiv_with(&config, My(&a, 0), My(&c, 0)));
+
+    let (a, b) = (cycle(3), cycle(7));
+    assert!(robust::equiv_with(&config, My(&a, 0), My(&b, 0)));
+    assert!(robust::equiv_with(&Config::default(), My(&a, 0), My(&b, 0)));
+}
+
+/// The limits of the config are what are used.
+#[test]
+fn limits_apply()
+{
+    let (a, b) = (degenerate_dag(1), degenerate_dag(1));
+    assert!(robust::precheck_equiv_with(&eager_config(), My(&a, 0), My(&b, 0)));
+    let eager_visits = a.visits.get();
+
+    let mut config = eager_config();
+    config.precheck_limit = 2000;
+    let (a, b) = (degenerate_dag(1), degenerate_dag(1));
+    assert!(robust::precheck_equiv_with(&config, My(&a, 0), My(&b, 0)));
+    let lazy_visits = a.visits.get();
+
+    assert!(eager_visits <= 4 * DEPTH as u64);
+    assert!(lazy_visits >= 2000);
+}
+
+/// A slow limit of zero is treated as one, so that cycles are still detected.
+#[test]
+fn zero_slow_limit()
+{
+    let mut config = eager_config();
+    config.slow_limit = 0;
+    let (a, b) = (cycle(3), cycle(7));
+    assert!(robust::equiv_with(&config, My(&a, 0), My(&b, 0)));
+    assert!(robust::precheck_equiv_with(&config, My(&a, 0), My(&b, 0)));
+}
+
+#[test]
+fn generic()
+{
+    struct Args<'l>(PhantomData<&'l ()>);
+
+    impl<'l> equiv::Params for Args<'l>
+    {
+        type DescendMode = Interleave<Self>;
+        type Error = Infallible;
+        type Node = My<'l>;
+        type RecurMode = RecurStack<Self>;
+    }
+
+    impl<'l> interleave::Params for Args<'l>
+    {
+        type Node = My<'l>;
+        type RNG = random::default::RandomNumberGenerator;
+        type Table = arena::Table<Self>;
+    }
+
+    impl<'l> arena::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    impl<'l> recursion::stack::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    impl<'l> precheck_interleave::Params<My<'l>> for Args<'l>
+    {
+        type Error = Infallible;
+        type InterleaveParams = Self;
+        type InterleaveRecurMode = RecurStack<Self>;
+        type PrecheckRecurMode = RecurStack<Self>;
+    }
+
+    let (a, b, c) = (degenerate_dag(1), degenerate_dag(1), degenerate_dag(2));
+    let config = eager_config();
+
+    let mut e = Equiv::<Args<'_>>::with_modes(
+        Interleave::with_config(&config),
+        RecurStack::with_capacity(2 * DEPTH),
+    );
+    assert_eq!(e.equiv(My(&a, 0), My(&b, 0)), Ok(true));
+    e.reset();
+    assert_eq!(e.equiv(My(&a, 0), My(&c, 0)), Ok(false));
+
+    assert_eq!(
+        precheck_interleave::equiv_with::<_, Args<'_>>(&config, My(&a, 0), My(&b, 0)),
+        Ok(true)
+    );
+    assert_eq!(
+        precheck_interleave::equiv_with::<_, Args<'_>>(&config, My(&a, 0), My(&c, 0)),
+        Ok(false)
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::robust::{
+        correspondence,
+        Correspondence,
+    },
+    std::collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// The correspondence by the indexes of the nodes in their arenas.
+fn indexes(
+    a: &Arena,
+    b: &Arena,
+) -> Result<BTreeMap<usize, BTreeSet<usize>>, bool>
+{
+    #[allow(clippy::mutable_key_type)] // The IDs are hashed by address, not by the `Cell`.
+    let c: Correspondence<My<'_>> = correspondence(My(a, 0), My(b, 0))?;
+    Ok(c.into_iter().map(|((_, a), bs)| (a, bs.into_iter().map(|(_, b)| b).collect())).collect())
+}
+
+fn map<const N: usize>(pairs: [(usize, &[usize]); N]) -> BTreeMap<usize, BTreeSet<usize>>
+{
+    pairs.into_iter().map(|(a, bs)| (a, bs.iter().copied().collect())).collect()
+}
+
+
+#[test]
+fn trees()
+{
+    let a = Arena::new(vec![(1, vec![1, 2]), (2, vec![]), (3, vec![])]);
+    let b = Arena::new(vec![(1, vec![2, 1]), (3, vec![]), (2, vec![])]);
+    assert_eq!(indexes(&a, &b), Ok(map([(0, &[0]), (1, &[2]), (2, &[1])])));
+}
+
+#[test]
+fn not_equiv()
+{
+    let a = Arena::new(vec![(1, vec![1, 2]), (2, vec![]), (3, vec![])]);
+    let b = Arena::new(vec![(1, vec![1, 2]), (2, vec![]), (4, vec![])]);
+    assert_eq!(indexes(&a, &b), Err(false));
+
+    let c = Arena::new(vec![(1, vec![1]), (2, vec![])]);
+    assert_eq!(indexes(&a, &c), Err(false));
+}
+
+#[test]
+fn unrolled_cycle()
+{
+    let a = Arena::new(vec![(1, vec![0])]);
+    let b = Arena::new(vec![(1, vec![1]), (1, vec![0])]);
+    assert_eq!(indexes(&a, &b), Ok(map([(0, &[0, 1])])));
+    assert_eq!(indexes(&b, &a), Ok(map([(0, &[0]), (1, &[0])])));
+}
+
+#[test]
+fn shared()
+{
+    let a = Arena::new(vec![(1, vec![1, 1]), (2, vec![2, 2]), (3, vec![])]);
+    let b = Arena::new(vec![
+        (1, vec![1, 2]),
+        (2, vec![3, 3]),
+        (2, vec![4, 4]),
+        (3, vec![]),
+        (3, vec![]),
+    ]);
+    assert_eq!(indexes(&a, &b), Ok(map([(0, &[0]), (1, &[1, 2]), (2, &[3, 4])])));
+}
+
+#[test]
+fn identical()
+{
+    let a = Arena::new(vec![(1, vec![1, 2]), (2, vec![2]), (3, vec![0])]);
+    assert_eq!(indexes(&a, &a), Ok(map([(0, &[0]), (1, &[1]), (2, &[2])])));
+}
+
+#[test]
+fn deep()
+{
+    const DEPTH: usize = 100_000;
+
+    let (a, b) = (Arena::list(DEPTH, 1), Arena::list(DEPTH, 1));
+    let c = indexes(&a, &b).unwrap();
+    assert_eq!(c.len(), DEPTH);
+    assert!(c.iter().all(|(a, bs)| bs.len() == 1 && bs.contains(a)));
+}
@@ -0,0 +1,232 @@
+use {
+    graph_safe_compare::{
+        basic,
+        cross::{
+            Cross,
+            CrossNode,
+        },
+        Node,
+    },
+    std::cmp::Ordering,
+};
+
+
+/// An in-memory form, where each node has its own vector of edges.
+struct Ast(Vec<(i32, Vec<usize>)>);
+
+/// A packed form, where the edges of all nodes are in a single vector, like a view of a
+/// serialized form would be.
+struct Packed
+{
+    vals:   Vec<i32>,
+    starts: Vec<usize>,
+    edges:  Vec<usize>,
+}
+
+impl From<&Ast> for Packed
+{
+    fn from(ast: &Ast) -> Self
+    {
+        let mut packed = Packed { vals: vec![], starts: vec![], edges: vec![] };
+        for (val, edges) in &ast.0 {
+            packed.vals.push(*val);
+            packed.starts.push(packed.edges.len());
+            packed.edges.extend(edges);
+        }
+        packed.starts.push(packed.edges.len());
+        packed
+    }
+}
+
+#[derive(Copy, Clone)]
+struct AstRef<'l>(&'l Ast, usize);
+
+#[derive(Copy, Clone)]
+struct View<'l>(&'l Packed, usize);
+
+impl<'l> Node for AstRef<'l>
+{
+    type Cmp = Ordering;
+    type Id = usize;
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        self.1
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        self.0.0[self.1].1.get(*index).map(|&i| AstRef(self.0, i))
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.0.0[self.1].0.cmp(&other.0.0[other.1].0)
+    }
+}
+
+impl<'l> View<'l>
+{
+    fn val(&self) -> i32
+    {
+        self.0.vals[self.1]
+    }
+}
+
+impl<'l> Node for View<'l>
+{
+    type Cmp = Ordering;
+    type Id = usize;
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        self.1
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        let (start, end) = (self.0.starts[self.1], self.0.starts[self.1 + 1]);
+        self.0.edges[start .. end].get(*index).map(|&i| View(self.0, i))
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.val().cmp(&other.val())
+    }
+}
+
+impl<'a, 'v> CrossNode<View<'v>> for AstRef<'a>
+{
+    fn cross_equiv_modulo_edges(
+        &self,
+        other: &View<'v>,
+    ) -> Self::Cmp
+    {
+        self.0.0[self.1].0.cmp(&other.val())
+    }
+}
+
+
+type My<'a, 'v> = Cross<AstRef<'a>, View<'v>>;
+
+fn roots<'a, 'v>(
+    ast: &'a Ast,
+    packed: &'v Packed,
+) -> (My<'a, 'v>, My<'a, 'v>)
+{
+    (Cross::A(AstRef(ast, 0)), Cross::B(View(packed, 0)))
+}
+
+fn acyclic() -> Ast
+{
+    Ast(vec![(1, vec![1, 2]), (2, vec![3]), (3, vec![3, 3]), (4, vec![])])
+}
+
+fn cyclic() -> Ast
+{
+    Ast(vec![(1, vec![1, 2]), (2, vec![0]), (3, vec![2, 1])])
+}
+
+fn long_list(length: usize) -> Ast
+{
+    Ast((0 .. length).map(|i| (0, if i + 1 < length { vec![i + 1] } else { vec![] })).collect())
+}
+
+
+#[test]
+fn basic()
+{
+    let ast = acyclic();
+    let packed = Packed::from(&ast);
+    let (a, b) = roots(&ast, &packed);
+    assert_eq!(basic::equiv(a, b), Ordering::Equal);
+}
+
+#[test]
+fn unequal()
+{
+    let ast1 = acyclic();
+    let mut ast2 = acyclic();
+    ast2.0[3].0 = 5;
+    let packed2 = Packed::from(&ast2);
+    let (a, b) = roots(&ast1, &packed2);
+    assert_eq!(basic::equiv(a, b), Ordering::Less);
+
+    let packed1 = Packed::from(&ast1);
+    let (a, b) = roots(&ast2, &packed1);
+    assert_eq!(basic::equiv(a, b), Ordering::Greater);
+
+    ast2.0[3].1.push(0);
+    let packed2 = Packed::from(&ast2);
+    let (a, b) = roots(&ast1, &packed2);
+    assert_eq!(basic::equiv(a, b), Ordering::Less);
+}
+
+/// The IDs of both sides are `0` but that must not cause them to be considered identical.
+#[test]
+fn separate_namespaces()
+{
+    let ast = Ast(vec![(1, vec![])]);
+    let packed = Packed::from(&Ast(vec![(2, vec![])]));
+    let (a, b) = roots(&ast, &packed);
+    assert_eq!(basic::equiv(a, b), Ordering::Less);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn deep_and_wide()
+{
+    use graph_safe_compare::{
+        deep_safe,
+        wide_safe,
+    };
+
+    let ast = long_list(1_000_000);
+    let packed = Packed::from(&ast);
+    let (a, b) = roots(&ast, &packed);
+    assert_eq!(deep_safe::equiv(a.clone(), b.clone()), Ordering::Equal);
+    assert_eq!(wide_safe::equiv(a, b), Ordering::Equal);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cyclic_safe()
+{
+    use graph_safe_compare::{
+        cycle_safe,
+        robust,
+    };
+
+    let ast = cyclic();
+    let packed = Packed::from(&ast);
+    let (a, b) = roots(&ast, &packed);
+    assert_eq!(cycle_safe::equiv(a.clone(), b.clone()), Ordering::Equal);
+    assert_eq!(cycle_safe::precheck_equiv(a.clone(), b.clone()), Ordering::Equal);
+    assert_eq!(robust::equiv(a.clone(), b.clone()), Ordering::Equal);
+    assert_eq!(robust::precheck_equiv(a, b), Ordering::Equal);
+
+    let mut ast2 = cyclic();
+    ast2.0[2].0 = 0;
+    let packed2 = Packed::from(&ast2);
+    let (a, b) = roots(&ast, &packed2);
+    assert_eq!(robust::equiv(a, b), Ordering::Greater);
+
+    let ast = long_list(1_000_000);
+    let packed = Packed::from(&ast);
+    let (a, b) = roots(&ast, &packed);
+    assert_eq!(robust::equiv(a, b), Ordering::Equal);
+}
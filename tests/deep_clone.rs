@@ -0,0 +1,143 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        robust,
+        utils::{
+            deep_clone,
+            NodeBuilder,
+        },
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// Creates the new nodes as elements of a new arena.
+#[derive(Default)]
+struct Builder(Vec<(i32, Vec<usize>)>);
+
+impl<'l> NodeBuilder<My<'l>> for Builder
+{
+    type Output = usize;
+
+    fn create(
+        &mut self,
+        source: &My<'l>,
+    ) -> Self::Output
+    {
+        self.0.push((*source.value(), vec![]));
+        self.0.len() - 1
+    }
+
+    fn add_edge(
+        &mut self,
+        node: &Self::Output,
+        edge: Self::Output,
+    )
+    {
+        self.0[*node].1.push(edge);
+    }
+}
+
+/// Copy the graph rooted at the first node of `arena` into a new arena.
+fn copy(arena: &Arena) -> (Arena, usize)
+{
+    let mut builder = Builder::default();
+    let root = deep_clone(My(arena, 0), &mut builder);
+    (Arena::new(builder.0), root)
+}
+
+/// ```text
+///     0
+///    / \
+///   1   2
+///  / \   \
+/// 3   4   5
+///      \ /
+///       6 -> 0
+/// ```
+fn diamond_with_cycle() -> Arena
+{
+    Arena::new(vec![
+        (0, vec![1, 2]),
+        (1, vec![3, 4]),
+        (2, vec![5]),
+        (3, vec![]),
+        (4, vec![6]),
+        (5, vec![6]),
+        (6, vec![0]),
+    ])
+}
+
+
+#[test]
+fn leaf()
+{
+    let arena = Arena::new(vec![(7, vec![])]);
+    let (copy, root) = copy(&arena);
+    assert_eq!(copy.nodes, [(7, vec![])]);
+    assert_eq!(root, 0);
+}
+
+#[test]
+fn shared_and_cyclic()
+{
+    let arena = diamond_with_cycle();
+    let (copy, root) = copy(&arena);
+    // Each source node is created exactly once.
+    assert_eq!(copy.nodes.len(), arena.nodes.len());
+    assert!(robust::equiv(My(&arena, 0), My(&copy, root)));
+
+    // The copy has the same shape, even if its nodes are arranged differently.
+    let mut values: Vec<_> = copy.nodes.iter().map(|(v, edges)| (*v, edges.len())).collect();
+    values.sort_unstable();
+    assert_eq!(values, [(0, 2), (1, 2), (2, 1), (3, 0), (4, 1), (5, 1), (6, 1)]);
+    let six = copy.nodes.iter().position(|(v, _)| *v == 6).unwrap();
+    assert_eq!(copy.nodes[six].1, [root]);
+}
+
+#[test]
+fn not_equiv_when_changed()
+{
+    let arena = diamond_with_cycle();
+    let (mut copy, root) = copy(&arena);
+    let three = copy.nodes.iter().position(|(v, _)| *v == 3).unwrap();
+    copy.nodes[three].0 = -3;
+    assert!(!robust::equiv(My(&arena, 0), My(&copy, root)));
+}
+
+#[test]
+fn only_reachable()
+{
+    let arena = Arena::new(vec![(0, vec![2]), (1, vec![0]), (2, vec![])]);
+    let (copy, root) = copy(&arena);
+    assert_eq!(copy.nodes.len(), 2);
+    assert!(robust::equiv(My(&arena, 0), My(&copy, root)));
+}
+
+#[test]
+fn degenerate()
+{
+    const DEPTH: usize = 100;
+
+    let mut nodes: Vec<_> = (0 .. DEPTH).map(|i| (0, vec![i + 1, i + 1])).collect();
+    nodes.push((1, vec![]));
+    let arena = Arena::new(nodes);
+    let (copy, root) = copy(&arena);
+    assert_eq!(copy.nodes.len(), DEPTH + 1);
+    assert!(robust::equiv(My(&arena, 0), My(&copy, root)));
+}
+
+#[test]
+fn long()
+{
+    const LENGTH: usize = 100_000;
+
+    let arena =
+        Arena::new((0 .. LENGTH).map(|i| (0, vec![i + 1])).chain([(1, vec![0])]).collect());
+    let (copy, root) = copy(&arena);
+    assert_eq!(copy.nodes.len(), LENGTH + 1);
+    assert!(robust::equiv(My(&arena, 0), My(&copy, root)));
+}
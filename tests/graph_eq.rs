@@ -0,0 +1,163 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        graph_eq::{
+            Basic,
+            CycleSafe,
+            DeepSafe,
+            GraphEq,
+            WideSafe,
+        },
+        utils::{
+            NodeHash,
+            RefId,
+        },
+        Node,
+    },
+    std::{
+        cmp::Ordering,
+        collections::{
+            BTreeMap,
+            HashSet,
+        },
+        hash::{
+            Hash,
+            Hasher,
+        },
+    },
+};
+
+
+#[derive(Clone, Debug)]
+enum Tree
+{
+    Leaf(i32),
+    Branch(Vec<Tree>),
+}
+
+use Tree::{
+    Branch,
+    Leaf,
+};
+
+impl Node for &Tree
+{
+    type Cmp = Ordering;
+    type Id = RefId<Self>;
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        RefId(*self)
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        match self {
+            Leaf(_) => None,
+            Branch(trees) => trees.get(*index),
+        }
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        match (self, other) {
+            (Leaf(a), Leaf(b)) => a.cmp(b),
+            (Leaf(_), Branch(_)) => Ordering::Less,
+            (Branch(_), Leaf(_)) => Ordering::Greater,
+            (Branch(_), Branch(_)) => Ordering::Equal,
+        }
+    }
+}
+
+impl NodeHash for &Tree
+{
+    fn hash_modulo_edges<H: Hasher>(
+        &self,
+        state: &mut H,
+    )
+    {
+        match self {
+            Leaf(val) => val.hash(state),
+            Branch(_) => state.write_u8(0xff),
+        }
+    }
+}
+
+/// A list of `len` branches, that ends with a leaf of `last_val`.
+fn list(
+    len: usize,
+    last_val: i32,
+) -> Tree
+{
+    (0 .. len).fold(Leaf(last_val), |next, _| Branch(vec![next]))
+}
+
+fn tree(vals: &[i32]) -> GraphEq<Tree>
+{
+    GraphEq::new(Branch(vals.iter().map(|&val| Leaf(val)).collect()))
+}
+
+
+#[test]
+fn eq()
+{
+    assert_eq!(tree(&[1, 2, 3]), tree(&[1, 2, 3]));
+    assert_ne!(tree(&[1, 2, 3]), tree(&[1, 2, 4]));
+    assert_ne!(tree(&[1, 2]), tree(&[1, 2, 3]));
+    assert_eq!(GraphEq::<_>::new(list(10_000, 1)), GraphEq::new(list(10_000, 1)));
+}
+
+#[test]
+fn ord()
+{
+    let mut trees = vec![tree(&[3]), tree(&[1, 2]), tree(&[]), tree(&[1]), tree(&[1, 1])];
+    trees.sort();
+    assert_eq!(trees, [tree(&[]), tree(&[1]), tree(&[1, 1]), tree(&[1, 2]), tree(&[3])]);
+    assert!(tree(&[1, 2]) < tree(&[2]));
+    assert_eq!(tree(&[5]).cmp(&tree(&[5])), Ordering::Equal);
+}
+
+#[test]
+fn map_keys()
+{
+    let mut map = BTreeMap::new();
+    map.insert(tree(&[1, 2]), "a");
+    map.insert(tree(&[3]), "b");
+    map.insert(tree(&[1, 2]), "c");
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&tree(&[1, 2])), Some(&"c"));
+
+    let set: HashSet<_> = [tree(&[1]), tree(&[2]), tree(&[1])].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&tree(&[2])));
+}
+
+#[test]
+fn strategies()
+{
+    fn check<S>()
+    where S: for<'t> graph_safe_compare::graph_eq::Strategy<&'t Tree>
+    {
+        let a = GraphEq::<_, S>::new(Branch(vec![Leaf(1), list(10, 2)]));
+        let b = GraphEq::<_, S>::from(Branch(vec![Leaf(1), list(10, 2)]));
+        let c = GraphEq::<_, S>::from(Branch(vec![Leaf(1), list(10, 3)]));
+        assert_eq!(a, b);
+        assert!(b < c);
+        assert_eq!(a.clone(), a);
+        assert!(matches!(&*a, Branch(trees) if trees.len() == 2));
+        assert!(matches!(c.into_inner(), Branch(_)));
+    }
+
+    check::<Basic>();
+    check::<CycleSafe>();
+    check::<DeepSafe>();
+    check::<WideSafe>();
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        basic::recursion::callstack::CallStack,
+        cycle_safe::modes::interleave::{
+            self,
+            random,
+            Interleave,
+        },
+        deep_safe::recursion::{
+            self,
+            queue::RecurQueue,
+        },
+        generic::{
+            equiv::{
+                self,
+                Equiv,
+            },
+            equiv_classes::premade::hash_map,
+        },
+        robust,
+    },
+    std::{
+        convert::Infallible,
+        marker::PhantomData,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+const SHARED_LENGTH: usize = 10_000;
+const AMOUNT_ROOTS: usize = 10;
+
+/// A forest of roots that all reference the same long shared list.  The roots are the last
+/// elements.
+fn forest(last_val: i32) -> Arena
+{
+    let mut nodes: Vec<_> = (0 .. SHARED_LENGTH)
+        .map(|i| (0, if i + 1 < SHARED_LENGTH { vec![i + 1] } else { vec![] }))
+        .collect();
+    nodes.last_mut().unwrap().0 = last_val;
+    nodes.extend((0 .. AMOUNT_ROOTS).map(|i| (i as i32, vec![0])));
+    Arena::new(nodes)
+}
+
+fn root_pairs<'a, 'b>(
+    a: &'a Arena,
+    b: &'b Arena,
+) -> impl Iterator<Item = (My<'a>, My<'b>)>
+{
+    (SHARED_LENGTH .. SHARED_LENGTH + AMOUNT_ROOTS).map(move |i| (My(a, i), My(b, i)))
+}
+
+
+#[test]
+fn equivalent()
+{
+    let (a, b) = (forest(1), forest(1));
+    assert!(robust::equiv_many(root_pairs(&a, &b)));
+    assert!(robust::equiv_many(Vec::<(My<'_>, My<'_>)>::new()));
+}
+
+#[test]
+fn inequivalent()
+{
+    let (a, b) = (forest(1), forest(2));
+    assert!(!robust::equiv_many(root_pairs(&a, &b)));
+    // Stopped after the first pair.
+    assert!(a.visits.get() <= SHARED_LENGTH as u64 + 1);
+
+    let (a, b) = (forest(1), forest(1));
+    let mut pairs: Vec<_> = root_pairs(&a, &b).collect();
+    pairs.push((My(&a, 0), My(&b, SHARED_LENGTH)));
+    assert!(!robust::equiv_many(pairs));
+}
+
+/// Sharing one table across the pairs avoids repeatedly traversing the shared list.
+#[test]
+fn retains_shared_knowledge()
+{
+    let (a, b) = (forest(1), forest(1));
+    assert!(robust::equiv_many(root_pairs(&a, &b)));
+    let many_visits = a.visits.get();
+
+    let (a, b) = (forest(1), forest(1));
+    assert!(root_pairs(&a, &b).all(|(a, b)| robust::equiv(a, b)));
+    let separate_visits = a.visits.get();
+
+    assert!(separate_visits >= (AMOUNT_ROOTS * SHARED_LENGTH) as u64);
+    assert!(many_visits < separate_visits / 2);
+}
+
+/// The generic method can be used with other variations.
+#[test]
+fn generic_call_stack()
+{
+    struct Args<'l>(PhantomData<&'l ()>);
+
+    impl<'l> equiv::Params for Args<'l>
+    {
+        type DescendMode = Interleave<Self>;
+        type Error = Infallible;
+        type Node = My<'l>;
+        type RecurMode = CallStack;
+    }
+
+    impl<'l> interleave::Params for Args<'l>
+    {
+        type Node = My<'l>;
+        type RNG = random::default::RandomNumberGenerator;
+        type Table = hash_map::Table<Self>;
+    }
+
+    impl<'l> hash_map::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    let arena =
+        |last| Arena::new(vec![(0, vec![1, 2]), (0, vec![2]), (last, vec![]), (0, vec![2, 1])]);
+    let (a, b) = (arena(1), arena(1));
+    let mut e = Equiv::<Args<'_>>::default();
+    assert_eq!(e.equiv_many([(My(&a, 0), My(&b, 0)), (My(&a, 3), My(&b, 3))]), Ok(true));
+
+    let c = arena(2);
+    let mut e = Equiv::<Args<'_>>::default();
+    assert_eq!(e.equiv_many([(My(&a, 3), My(&b, 3)), (My(&a, 0), My(&c, 0))]), Ok(false));
+}
+
+/// A reset instance forgets what it learned, but can be reused for independent comparisons.
+#[test]
+fn reset()
+{
+    struct Args<'l>(PhantomData<&'l ()>);
+
+    impl<'l> equiv::Params for Args<'l>
+    {
+        type DescendMode = Interleave<Self>;
+        type Error = Infallible;
+        type Node = My<'l>;
+        type RecurMode = RecurQueue<Self>;
+    }
+
+    impl<'l> interleave::Params for Args<'l>
+    {
+        type Node = My<'l>;
+        type RNG = random::default::RandomNumberGenerator;
+        type Table = hash_map::Table<Self>;
+    }
+
+    impl<'l> hash_map::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    impl<'l> recursion::queue::Params for Args<'l>
+    {
+        type Node = My<'l>;
+    }
+
+    let (a, b, c) = (forest(1), forest(1), forest(2));
+    let mut e = Equiv::<Args<'_>>::default();
+
+    for (ar, br) in root_pairs(&a, &b) {
+        assert_eq!(e.equiv(ar, br), Ok(true));
+        e.reset();
+    }
+    // Nothing was retained across the pairs.
+    assert!(a.visits.get() >= (AMOUNT_ROOTS * SHARED_LENGTH) as u64);
+
+    // Reusable after an inequivalent result, which might leave elements in the queue.
+    for (ar, cr) in root_pairs(&a, &c) {
+        assert_eq!(e.equiv(ar, cr), Ok(false));
+        e.reset();
+    }
+    assert_eq!(e.equiv_many(root_pairs(&a, &b)), Ok(true));
+}
@@ -1,5 +1,3 @@
-#[cfg(feature = "alloc")]
-use graph_safe_compare::wide_safe;
 #[cfg(feature = "std")]
 use graph_safe_compare::{
     cycle_safe,
@@ -15,6 +13,27 @@ use {
     Datum::*,
     Ordering::*,
 };
+#[cfg(feature = "alloc")]
+use {
+    graph_safe_compare::{
+        basic::modes::unlimited::Unlimited,
+        generic::equiv::{
+            self,
+            Equiv,
+        },
+        wide_safe::{
+            self,
+            recursion::hybrid::{
+                self,
+                Hybrid,
+            },
+        },
+    },
+    std::{
+        convert::Infallible,
+        marker::PhantomData,
+    },
+};
 
 
 #[derive(Debug, Eq, PartialEq)]
@@ -114,7 +133,12 @@ macro_rules! case {
         }
 
         #[cfg(feature = "alloc")]
-        assert_eq!(wide_safe::equiv(a, b), r);
+        {
+            assert_eq!(wide_safe::equiv(a, b), r);
+            for max_call_depth in [0, 1, 2, usize::MAX] {
+                assert_eq!(hybrid_equiv(max_call_depth, a, b), r);
+            }
+        }
 
         assert_eq!(basic::equiv(a, b), r);
         assert_eq!(basic::limited_equiv(usize::MAX, a, b).unwrap(), r);
@@ -123,6 +147,37 @@ macro_rules! case {
     }};
 }
 
+/// Uses the call-stack only up to `max_call_depth`, to exercise changing to the vector-stack at
+/// various depths.
+#[cfg(feature = "alloc")]
+fn hybrid_equiv<'l>(
+    max_call_depth: usize,
+    a: &'l Datum,
+    b: &'l Datum,
+) -> Ordering
+{
+    struct Args<'l>(PhantomData<&'l ()>);
+
+    impl<'l> equiv::Params for Args<'l>
+    {
+        type DescendMode = Unlimited;
+        type Error = Infallible;
+        type Node = &'l Datum;
+        type RecurMode = Hybrid<Self>;
+    }
+
+    impl<'l> hybrid::Params for Args<'l>
+    {
+        type Node = &'l Datum;
+    }
+
+    let mut e = Equiv::<Args<'l>>::with_modes(
+        Unlimited,
+        Hybrid::default().with_max_call_depth(max_call_depth),
+    );
+    e.equiv(a, b).unwrap()
+}
+
 
 fn a() -> Box<Datum>
 {
@@ -0,0 +1,159 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        robust::partition,
+        utils::{
+            fingerprint,
+            NodeHash,
+            FINGERPRINT_LIMIT,
+        },
+    },
+    std::{
+        collections::hash_map::DefaultHasher,
+        hash::{
+            Hash,
+            Hasher,
+        },
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// A value that is not hashed, so that all graphs of the same shape have the same fingerprint.
+#[derive(PartialEq)]
+struct Unhashed(i32);
+
+impl NodeHash for My<'_>
+{
+    fn hash_modulo_edges<H: Hasher>(
+        &self,
+        state: &mut H,
+    )
+    {
+        self.value().hash(state);
+    }
+}
+
+impl NodeHash for My<'_, Unhashed>
+{
+    fn hash_modulo_edges<H: Hasher>(
+        &self,
+        _state: &mut H,
+    )
+    {
+    }
+}
+
+/// Cycles of various lengths are equivalent to each other (because their unfoldings are the
+/// same infinite list), but not to a finite list nor to a cycle of different values.
+fn cycles_and_lists<V>(value: fn(i32) -> V) -> Arena<V>
+{
+    Arena::new(
+        [
+            /* 0 */ (0, vec![0]),
+            /* 1 */ (0, vec![2]),
+            /* 2 */ (0, vec![1]),
+            /* 3 */ (0, vec![4]),
+            /* 4 */ (0, vec![]),
+            /* 5 */ (1, vec![5]),
+            /* 6 */ (0, vec![7]),
+            /* 7 */ (0, vec![8]),
+            /* 8 */ (0, vec![6]),
+            /* 9 */ (0, vec![4]),
+            /* 10 */ (1, vec![10]),
+        ]
+        .into_iter()
+        .map(|(v, edges)| (value(v), edges))
+        .collect(),
+    )
+}
+
+fn roots<V>(a: &Arena<V>) -> Vec<My<'_, V>>
+{
+    (0 .. a.nodes.len()).map(|i| My(a, i)).collect()
+}
+
+
+#[test]
+fn empty()
+{
+    let a = Arena::<i32>::new(vec![]);
+    assert_eq!(partition(&roots(&a)), Vec::<Vec<usize>>::new());
+}
+
+#[test]
+fn classes()
+{
+    let expected = vec![vec![0, 1, 2, 6, 7, 8], vec![3, 9], vec![4], vec![5, 10]];
+
+    let a = cycles_and_lists(|v| v);
+    assert_eq!(partition(&roots(&a)), expected);
+
+    // Same result when all graphs of the same shape have the same fingerprint.
+    let a = cycles_and_lists(Unhashed);
+    assert_eq!(partition(&roots(&a)), expected);
+}
+
+/// Graphs with different fingerprints are never compared.
+#[test]
+fn avoids_comparisons()
+{
+    const AMOUNT: usize = 1000;
+
+    let a = Arena::new((0 .. AMOUNT as i32).map(|v| (v, vec![])).collect());
+    let classes = partition(&roots(&a));
+    assert_eq!(classes.len(), AMOUNT);
+    assert!(classes.iter().enumerate().all(|(i, c)| c == &[i]));
+    assert!(a.visits.get() < AMOUNT as u64 / 10);
+
+    let a = Arena::new((0 .. AMOUNT as i32).map(|_| (0, vec![])).collect());
+    let classes = partition(&roots(&a));
+    assert_eq!(classes, vec![(0 .. AMOUNT).collect::<Vec<_>>()]);
+    assert_eq!(a.visits.get(), AMOUNT as u64 - 1);
+}
+
+/// The amounts of edges that are fingerprinted are capped at the limit, so that very-wide nodes
+/// are not walked entirely.
+#[test]
+fn caps_edges()
+{
+    let fingerprint_of = |width| {
+        let a = Arena::new(vec![(0, vec![1; width]), (1, vec![])]);
+        let mut hasher = DefaultHasher::new();
+        fingerprint(My(&a, 0), FINGERPRINT_LIMIT, &mut hasher);
+        hasher.finish()
+    };
+
+    assert_ne!(fingerprint_of(FINGERPRINT_LIMIT - 1), fingerprint_of(FINGERPRINT_LIMIT));
+    assert_eq!(fingerprint_of(FINGERPRINT_LIMIT), fingerprint_of(10 * FINGERPRINT_LIMIT));
+}
+
+/// The table is retained across comparisons, so a long list shared by all the graphs of each
+/// arena is only traversed about once.
+#[test]
+fn retains_shared_knowledge()
+{
+    const SHARED_LENGTH: usize = 10_000;
+    const AMOUNT_ROOTS: usize = 10;
+
+    let forest = || {
+        let mut nodes: Vec<_> = (0 .. SHARED_LENGTH)
+            .map(|i| (0, if i + 1 < SHARED_LENGTH { vec![i + 1] } else { vec![] }))
+            .collect();
+        nodes.extend((0 .. AMOUNT_ROOTS).map(|_| (-1, vec![0])));
+        Arena::new(nodes)
+    };
+    let (a, b) = (forest(), forest());
+
+    let graphs: Vec<_> = (SHARED_LENGTH .. SHARED_LENGTH + AMOUNT_ROOTS)
+        .flat_map(|i| [My(&a, i), My(&b, i)])
+        .collect();
+    let classes = partition(&graphs);
+    assert_eq!(classes, vec![(0 .. 2 * AMOUNT_ROOTS).collect::<Vec<_>>()]);
+    // The representative is from `a`, and so its visits count all the comparisons.
+    assert!(a.visits.get() >= SHARED_LENGTH as u64);
+    assert!(a.visits.get() < 2 * SHARED_LENGTH as u64);
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::utils::{
+        deep_safe_drop,
+        TakeEdges,
+    },
+    std::{
+        cell::{
+            Cell,
+            RefCell,
+        },
+        mem,
+        rc::Rc,
+    },
+};
+
+
+/// Deep enough that recursive dropping would overflow the call-stack.
+const DEPTH: usize = 1_000_000;
+
+/// Counts how many nodes are dropped.
+struct Token<'l>(&'l Cell<usize>);
+
+impl<'l> Drop for Token<'l>
+{
+    fn drop(&mut self)
+    {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+/// Shared ownership with interior mutability, to be able to form cycles.
+#[derive(Clone)]
+struct My<'l>(Rc<RefCell<Inner<'l>>>);
+
+struct Inner<'l>
+{
+    edges:  Vec<My<'l>>,
+    _token: Token<'l>,
+}
+
+impl<'l> My<'l>
+{
+    fn new(
+        edges: Vec<Self>,
+        dropped: &'l Cell<usize>,
+    ) -> Self
+    {
+        My(Rc::new(RefCell::new(Inner { edges, _token: Token(dropped) })))
+    }
+
+    /// A list of `len` nodes.
+    fn list(
+        len: usize,
+        dropped: &'l Cell<usize>,
+    ) -> Self
+    {
+        let tail = My::new(vec![], dropped);
+        (1 .. len).fold(tail, |next, _| My::new(vec![next], dropped))
+    }
+
+    /// A chain of `len` nodes that each have two edges to the next.
+    fn degenerate(
+        len: usize,
+        dropped: &'l Cell<usize>,
+    ) -> Self
+    {
+        let tail = My::new(vec![], dropped);
+        (1 .. len).fold(tail, |next, _| My::new(vec![next.clone(), next], dropped))
+    }
+
+    /// A cycle of `len` nodes.
+    fn cycle(
+        len: usize,
+        dropped: &'l Cell<usize>,
+    ) -> Self
+    {
+        let head = My::new(vec![], dropped);
+        let last = (1 .. len).fold(head.clone(), |next, _| My::new(vec![next], dropped));
+        head.0.borrow_mut().edges.push(last);
+        head
+    }
+}
+
+/// Takes the edges regardless of sharing, which breaks cycles.
+impl<'l> TakeEdges for My<'l>
+{
+    type Edges = Vec<Self>;
+
+    fn take_edges(&mut self) -> Self::Edges
+    {
+        mem::take(&mut self.0.borrow_mut().edges)
+    }
+}
+
+/// Owns a graph and drops it safely.
+struct Graph<'l>(My<'l>);
+
+impl<'l> Drop for Graph<'l>
+{
+    fn drop(&mut self)
+    {
+        deep_safe_drop([self.0.clone()]);
+    }
+}
+
+/// Exclusive ownership, where only the last owner of a node takes its edges.
+struct Unique
+{
+    edges:  Vec<Rc<Unique>>,
+    /// Counts how many nodes are not dropped.
+    _alive: Rc<()>,
+}
+
+impl TakeEdges for Unique
+{
+    type Edges = Vec<Self>;
+
+    fn take_edges(&mut self) -> Self::Edges
+    {
+        mem::take(&mut self.edges)
+            .into_iter()
+            .filter_map(|edge| Rc::try_unwrap(edge).ok())
+            .collect()
+    }
+}
+
+
+#[test]
+fn long_list()
+{
+    let dropped = Cell::new(0);
+    deep_safe_drop([My::list(DEPTH, &dropped)]);
+    assert_eq!(dropped.get(), DEPTH);
+}
+
+#[test]
+fn degenerate()
+{
+    let dropped = Cell::new(0);
+    deep_safe_drop([My::degenerate(DEPTH, &dropped)]);
+    assert_eq!(dropped.get(), DEPTH);
+}
+
+#[test]
+fn cyclic()
+{
+    let dropped = Cell::new(0);
+    let (a, b) = (My::cycle(DEPTH, &dropped), My::cycle(3, &dropped));
+    deep_safe_drop([a, b]);
+    assert_eq!(dropped.get(), DEPTH + 3);
+}
+
+#[test]
+fn drop_impl()
+{
+    let dropped = Cell::new(0);
+    drop(Graph(My::cycle(DEPTH, &dropped)));
+    assert_eq!(dropped.get(), DEPTH);
+}
+
+#[test]
+fn only_last_owner()
+{
+    let alive = Rc::new(());
+    let shared = Rc::new(Unique { edges: vec![], _alive: Rc::clone(&alive) });
+    let node = |edges| Unique { edges, _alive: Rc::clone(&alive) };
+    let kept = node(vec![Rc::clone(&shared)]);
+
+    let long = (0 .. DEPTH).fold(node(vec![shared]), |next, _| node(vec![Rc::new(next)]));
+    deep_safe_drop([long]);
+    // Only the nodes that are still referenced remain.
+    assert_eq!(Rc::strong_count(&alive), 3);
+    assert_eq!(kept.edges.len(), 1);
+
+    deep_safe_drop([kept]);
+    assert_eq!(Rc::strong_count(&alive), 1);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        cycle_safe::{
+            self,
+            modes::interleave::random::{
+                default::SeededRandomNumberGenerator,
+                NumberGenerator as _,
+                SeedableNumberGenerator as _,
+            },
+        },
+        robust,
+    },
+    std::{
+        collections::HashSet,
+        num::NonZeroU16,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// A list, that is long enough to end the initial "slow" phase, that leads to nodes that each
+/// have two edges to the next, so the amount of nodes traversed depends on the randomized limits
+/// of the "fast" phase.
+fn list_then_degenerate() -> Arena
+{
+    const LIST_LENGTH: usize = 100;
+    const DEPTH: usize = 30;
+
+    let mut nodes: Vec<_> = (0 .. LIST_LENGTH).map(|i| (0, vec![i + 1])).collect();
+    nodes.extend((LIST_LENGTH .. LIST_LENGTH + DEPTH).map(|i| (0, vec![i + 1, i + 1])));
+    nodes.push((1, vec![]));
+    Arena::new(nodes)
+}
+
+/// The amount of nodes traversed by comparing with `f`.
+fn visits(f: impl Fn(My<'_>, My<'_>) -> bool) -> u64
+{
+    let (a, b) = (list_then_degenerate(), list_then_degenerate());
+    assert!(f(My(&a, 0), My(&b, 0)));
+    a.visits.get()
+}
+
+
+#[test]
+fn same_sequence()
+{
+    let sequence = |seed| {
+        let mut rng = SeededRandomNumberGenerator::from_seed(seed);
+        (0 .. 100).map(|_| rng.rand_upto(NonZeroU16::new(1000).unwrap())).collect::<Vec<_>>()
+    };
+
+    assert_eq!(sequence(42), sequence(42));
+    assert_ne!(sequence(42), sequence(43));
+}
+
+#[test]
+fn reproducible()
+{
+    for seed in [0, 1, 0xdead_beef] {
+        let first = visits(|a, b| robust::equiv_seeded(seed, a, b));
+        assert_eq!(visits(|a, b| robust::equiv_seeded(seed, a, b)), first);
+
+        let first = visits(|a, b| cycle_safe::equiv_seeded(seed, a, b));
+        assert_eq!(visits(|a, b| cycle_safe::equiv_seeded(seed, a, b)), first);
+
+        let first = visits(|a, b| robust::precheck_equiv_seeded(seed, a, b));
+        assert_eq!(visits(|a, b| robust::precheck_equiv_seeded(seed, a, b)), first);
+
+        let first = visits(|a, b| cycle_safe::precheck_equiv_seeded(seed, a, b));
+        assert_eq!(visits(|a, b| cycle_safe::precheck_equiv_seeded(seed, a, b)), first);
+    }
+}
+
+#[test]
+fn varies()
+{
+    let distinct: HashSet<_> =
+        (0 .. 10).map(|seed| visits(|a, b| robust::equiv_seeded(seed, a, b))).collect();
+    assert!(distinct.len() > 1);
+}
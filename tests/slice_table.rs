@@ -0,0 +1,157 @@
+//! Use of the allocation-free table, which does not require any features.
+
+use {
+    core::{
+        marker::PhantomData,
+        num::NonZeroU16,
+    },
+    graph_safe_compare::{
+        basic::recursion::callstack::CallStack,
+        cycle_safe::modes::interleave::{
+            self,
+            random,
+            Interleave,
+        },
+        generic::{
+            equiv::{
+                self,
+                Equiv,
+            },
+            equiv_classes::premade::slice::{
+                self,
+                BuildFnvHasher,
+                Slot,
+                Table,
+                TableFull,
+            },
+        },
+        Node,
+    },
+};
+
+
+/// Nodes are elements of a slice, and edges are indexes of other elements.
+#[derive(Copy, Clone)]
+struct My<'l>(&'l [(i32, &'l [usize])], usize);
+
+impl<'l> Node for My<'l>
+{
+    type Cmp = bool;
+    type Id = usize;
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        self.1
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        self.0[self.1].1.get(*index).map(|&i| My(self.0, i))
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        self.0[self.1].0 == other.0[other.1].0
+    }
+}
+
+#[derive(Default)]
+struct PseudoPseudoRNG(u32);
+
+impl random::NumberGenerator for PseudoPseudoRNG
+{
+    fn rand_upto(
+        &mut self,
+        exclusive_end: NonZeroU16,
+    ) -> u16
+    {
+        self.0 = self.0.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        (self.0 >> 16) as u16 % exclusive_end
+    }
+}
+
+struct Args<'l>(PhantomData<&'l ()>);
+
+impl<'l> equiv::Params for Args<'l>
+{
+    type DescendMode = Interleave<Self>;
+    type Error = TableFull;
+    type Node = My<'l>;
+    type RecurMode = CallStack;
+}
+
+impl<'l> interleave::Params for Args<'l>
+{
+    type Node = My<'l>;
+    type RNG = PseudoPseudoRNG;
+    type Table = Table<'l, Self>;
+
+    // Small limits so that the "slow" phase is reached soon.
+    const PRECHECK_LIMIT: u16 = 10;
+}
+
+impl<'l> slice::Params for Args<'l>
+{
+    type BuildHasher = BuildFnvHasher;
+    type Node = My<'l>;
+}
+
+fn equiv<'l>(
+    slots: &'l mut [Slot<usize>],
+    a: My<'l>,
+    b: My<'l>,
+) -> Result<bool, TableFull>
+{
+    let mut e = Equiv::<Args<'l>>::new(Interleave::with_table(Table::new(slots)));
+    e.equiv(a, b)
+}
+
+/// A cycle of `len` nodes that all have the value `val`, starting at `start`.
+fn cycle(
+    start: usize,
+    len: usize,
+    val: i32,
+) -> impl Iterator<Item = (i32, Vec<usize>)>
+{
+    (start .. start + len)
+        .map(move |i| (val, vec![if i + 1 < start + len { i + 1 } else { start }]))
+}
+
+
+#[test]
+fn cyclic()
+{
+    let owned: Vec<_> = cycle(0, 3, 1).chain(cycle(3, 5, 1)).chain(cycle(8, 4, 2)).collect();
+    let nodes: Vec<_> = owned.iter().map(|(v, e)| (*v, e.as_slice())).collect();
+    let mut slots = [Slot::EMPTY; 32];
+
+    assert_eq!(equiv(&mut slots, My(&nodes, 0), My(&nodes, 3)), Ok(true));
+    // Reusing the same buffer.
+    assert_eq!(equiv(&mut slots, My(&nodes, 0), My(&nodes, 8)), Ok(false));
+    assert_eq!(equiv(&mut slots, My(&nodes, 1), My(&nodes, 7)), Ok(true));
+}
+
+#[test]
+fn table_full()
+{
+    const LEN: usize = 1000;
+
+    // Two long lists, that are not cyclic, so that many distinct nodes are recorded.
+    let owned: Vec<_> = (0 .. 2 * LEN)
+        .map(|i| (0, if i + 1 == LEN || i + 1 == 2 * LEN { vec![] } else { vec![i + 1] }))
+        .collect();
+    let nodes: Vec<_> = owned.iter().map(|(v, e)| (*v, e.as_slice())).collect();
+
+    let mut slots = [Slot::EMPTY; 8];
+    assert_eq!(equiv(&mut slots, My(&nodes, 0), My(&nodes, LEN)), Err(TableFull));
+
+    let mut slots: Vec<_> = (0 .. 2 * LEN).map(|_| Slot::default()).collect();
+    assert_eq!(equiv(&mut slots, My(&nodes, 0), My(&nodes, LEN)), Ok(true));
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::{
+        robust,
+        snapshot::{
+            encode,
+            EncodeValue,
+            View,
+            MAGIC,
+        },
+        Node,
+    },
+    std::cmp::Ordering,
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// Big-endian, so that the bytes are ordered the same as the values.
+impl EncodeValue for My<'_, u32>
+{
+    fn encode_value(
+        &self,
+        out: &mut Vec<u8>,
+    )
+    {
+        out.extend_from_slice(&self.value().to_be_bytes());
+    }
+}
+
+fn snapshot(arena: &Arena<u32>) -> Vec<u8>
+{
+    encode(My(arena, 0)).unwrap()
+}
+
+
+#[test]
+fn view()
+{
+    let arena = Arena::new(vec![(1, vec![1, 2]), (2, vec![]), (3, vec![0])]);
+    let bytes = snapshot(&arena);
+    let root = View::new(&bytes).unwrap();
+
+    assert_eq!(root.value(), 1_u32.to_be_bytes());
+    assert_eq!(root.edge_count(), 2);
+    let (left, right) = (root.get_edge(&0).unwrap(), root.get_edge(&1).unwrap());
+    assert!(root.get_edge(&2).is_none());
+    assert_eq!(left.value(), 2_u32.to_be_bytes());
+    assert_eq!(left.edge_count(), 0);
+    assert_eq!(right.value(), 3_u32.to_be_bytes());
+    assert!(right.get_edge(&0).unwrap().id() == root.id());
+}
+
+#[test]
+fn shared_encoded_once()
+{
+    let (small, large) = (Arena::degenerate_cyclic(1, 1), Arena::degenerate_cyclic(1000, 1));
+    let record_len = snapshot(&small).len() - MAGIC.len();
+    assert_eq!(snapshot(&large).len(), MAGIC.len() + 1000 * record_len);
+}
+
+#[test]
+fn equiv_views()
+{
+    let (a, b) =
+        (snapshot(&Arena::degenerate_cyclic(100, 1)), snapshot(&Arena::degenerate_cyclic(3, 1)));
+    let (a, b) = (View::new(&a).unwrap(), View::new(&b).unwrap());
+    assert_eq!(robust::equiv(a, b), Ordering::Equal);
+    assert_eq!(robust::equiv(a, a), Ordering::Equal);
+
+    let c = snapshot(&Arena::new(vec![(1, vec![1, 1]), (2, vec![0, 0])]));
+    let c = View::new(&c).unwrap();
+    assert_eq!(robust::equiv(a, c), Ordering::Less);
+    assert_eq!(robust::equiv(c, b), Ordering::Greater);
+}
+
+#[test]
+fn equiv_amount_edges()
+{
+    let a = snapshot(&Arena::new(vec![(1, vec![1]), (2, vec![])]));
+    let b = snapshot(&Arena::new(vec![(1, vec![1, 1]), (2, vec![])]));
+    let (a, b) = (View::new(&a).unwrap(), View::new(&b).unwrap());
+    assert_eq!(robust::equiv(a, b), Ordering::Less);
+    assert_eq!(robust::equiv(b, a), Ordering::Greater);
+}
+
+#[test]
+fn deep()
+{
+    const DEPTH: usize = 100_000;
+
+    let (a, b) = (snapshot(&Arena::list(DEPTH, 1)), snapshot(&Arena::list(DEPTH, 1)));
+    let (a, b) = (View::new(&a).unwrap(), View::new(&b).unwrap());
+    assert_eq!(robust::equiv(a, b), Ordering::Equal);
+}
+
+#[test]
+fn malformed()
+{
+    let bytes = snapshot(&Arena::new(vec![(1, vec![1]), (2, vec![])]));
+    assert!(View::new(&bytes).is_ok());
+
+    assert_eq!(View::new(&[]).unwrap_err().offset, 0);
+    assert_eq!(View::new(b"nope").unwrap_err().offset, 0);
+    assert_eq!(View::new(&MAGIC).unwrap_err().offset, MAGIC.len());
+    assert_eq!(View::new(&bytes[.. bytes.len() - 1]).unwrap_err().offset, 20);
+
+    // The edge of the root does not reference the start of a record.
+    let mut bad_edge = bytes.clone();
+    bad_edge[16] += 1;
+    assert_eq!(View::new(&bad_edge).unwrap_err().offset, 16);
+
+    let mut trailing = bytes;
+    trailing.push(0);
+    assert!(View::new(&trailing).is_err());
+}
@@ -0,0 +1,239 @@
+use {
+    graph_safe_compare::{
+        testing::{
+            check_node_contract,
+            check_node_contract_limited,
+            Violation,
+        },
+        Cmp,
+        Node,
+    },
+    std::{
+        cell::Cell,
+        cmp::Ordering,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// Which rule a node type breaks.
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Bug
+{
+    None,
+    UnstableId,
+    NondeterministicEdge,
+    NotReflexive,
+    NotSymmetric,
+}
+
+/// An arena whose nodes break a rule.
+#[derive(Debug)]
+struct BuggyArena
+{
+    arena:   Arena<()>,
+    bug:     Bug,
+    counter: Cell<usize>,
+}
+
+/// Wraps the correct node type, to break the rule of its arena.
+#[derive(Copy, Clone, Debug)]
+struct Buggy<'l>
+{
+    arena: &'l BuggyArena,
+    node:  My<'l, ()>,
+}
+
+impl<'l> Buggy<'l>
+{
+    fn root(arena: &'l BuggyArena) -> Self
+    {
+        Buggy { arena, node: My(&arena.arena, 0) }
+    }
+
+    /// Gives a different value each time.
+    fn count(&self) -> usize
+    {
+        let count = self.arena.counter.get();
+        self.arena.counter.set(count + 1);
+        count
+    }
+}
+
+impl<'l> Node for Buggy<'l>
+{
+    type Cmp = bool;
+    type Id = <My<'l, ()> as Node>::Id;
+    type Index = usize;
+
+    fn id(&self) -> Self::Id
+    {
+        match self.arena.bug {
+            Bug::UnstableId => (self.node.id().0, self.count()),
+            _ => self.node.id(),
+        }
+    }
+
+    fn get_edge(
+        &self,
+        index: &Self::Index,
+    ) -> Option<Self>
+    {
+        let edge = self.node.get_edge(index).map(|node| Buggy { node, ..*self });
+        match self.arena.bug {
+            Bug::NondeterministicEdge if self.count() % 2 == 1 => None,
+            _ => edge,
+        }
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        other: &Self,
+    ) -> Self::Cmp
+    {
+        match self.arena.bug {
+            Bug::NotReflexive => self.node.1 != other.node.1,
+            Bug::NotSymmetric => self.node.1 <= other.node.1,
+            _ => self.node.equiv_modulo_edges(&other.node),
+        }
+    }
+}
+
+/// ```text
+/// 0 -> 1 -> 2 -> 0
+///       \
+///        3
+/// ```
+fn arena(bug: Bug) -> BuggyArena
+{
+    let arena = Arena::from_edges(vec![vec![1], vec![2, 3], vec![0], vec![]]);
+    BuggyArena { arena, bug, counter: Cell::new(0) }
+}
+
+fn check(bug: Bug) -> Vec<String>
+{
+    let arena = arena(bug);
+    check_node_contract(Buggy::root(&arena))
+        .into_iter()
+        .map(|violation| match violation {
+            Violation::UnstableId { node } => format!("UnstableId {}", node.node.1),
+            Violation::NondeterministicEdge { node, index } =>
+                format!("NondeterministicEdge {} {}", node.node.1, index),
+            Violation::NotReflexive { node } => format!("NotReflexive {}", node.node.1),
+            Violation::NotSymmetric { node, index } =>
+                format!("NotSymmetric {} {}", node.node.1, index),
+            other => format!("{:?}", other),
+        })
+        .collect()
+}
+
+
+#[test]
+fn valid()
+{
+    assert!(check(Bug::None).is_empty());
+}
+
+#[test]
+fn unstable_id()
+{
+    let violations = check(Bug::UnstableId);
+    // The traversal does not recognize the nodes already visited, and so repeats them.
+    assert_eq!(violations.len(), 10_000);
+    assert!(violations.iter().all(|v| v.starts_with("UnstableId")));
+
+    let arena = arena(Bug::UnstableId);
+    assert_eq!(check_node_contract_limited(Buggy::root(&arena), 3).len(), 3);
+}
+
+#[test]
+fn nondeterministic_edge()
+{
+    assert_eq!(check(Bug::NondeterministicEdge), [
+        "NondeterministicEdge 0 0",
+        "NotEquivToItself",
+    ]);
+}
+
+#[test]
+fn not_reflexive()
+{
+    assert_eq!(check(Bug::NotReflexive), [
+        "NotReflexive 0",
+        "NotReflexive 1",
+        "NotReflexive 2",
+        "NotReflexive 3",
+        "NotEquivToItself",
+    ]);
+}
+
+#[test]
+fn not_symmetric()
+{
+    // Every edge is to a node with a different index.
+    assert_eq!(check(Bug::NotSymmetric), [
+        "NotSymmetric 0 0",
+        "NotSymmetric 1 0",
+        "NotSymmetric 1 1",
+        "NotSymmetric 2 0",
+    ]);
+}
+
+
+/// Represents equivalence inversely, which breaks the rules of `Cmp`.
+struct Inverted(bool);
+
+impl Cmp for Inverted
+{
+    fn new_equiv() -> Self
+    {
+        Inverted(true)
+    }
+
+    fn is_equiv(&self) -> bool
+    {
+        !self.0
+    }
+
+    fn from_ord(ord: Ordering) -> Self
+    {
+        Inverted(ord.is_ne())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Leaf;
+
+impl Node for Leaf
+{
+    type Cmp = Inverted;
+    type Id = ();
+    type Index = u8;
+
+    fn id(&self) -> Self::Id {}
+
+    fn get_edge(
+        &self,
+        _index: &Self::Index,
+    ) -> Option<Self>
+    {
+        None
+    }
+
+    fn equiv_modulo_edges(
+        &self,
+        _other: &Self,
+    ) -> Self::Cmp
+    {
+        Inverted(false)
+    }
+}
+
+#[test]
+fn cmp()
+{
+    let violations = check_node_contract(Leaf);
+    assert!(matches!(violations[..], [Violation::NewEquivNotEquiv]));
+}
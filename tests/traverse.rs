@@ -0,0 +1,126 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::utils::{
+        traverse,
+        Order,
+        Traverse,
+    },
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+/// ```text
+///     0
+///    / \
+///   1   2
+///  / \   \
+/// 3   4   5
+///      \ /
+///       6 -> 0
+/// ```
+fn diamond_with_cycle() -> Arena<()>
+{
+    Arena::from_edges(vec![vec![1, 2], vec![3, 4], vec![5], vec![], vec![6], vec![6], vec![0]])
+}
+
+fn visits(
+    arena: &Arena<()>,
+    order: Order,
+) -> Vec<(usize, usize, Option<usize>)>
+{
+    Traverse::new(My(arena, 0), order).map(|v| (v.node.1, v.depth, v.index)).collect()
+}
+
+
+#[test]
+fn depth_first()
+{
+    let arena = diamond_with_cycle();
+    assert_eq!(visits(&arena, Order::DepthFirst), [
+        (0, 0, None),
+        (1, 1, Some(0)),
+        (3, 2, Some(0)),
+        (4, 2, Some(1)),
+        (6, 3, Some(0)),
+        (2, 1, Some(1)),
+        (5, 2, Some(0)),
+    ]);
+    assert!(traverse(My(&arena, 0)).map(|v| v.node.1).eq([0, 1, 3, 4, 6, 2, 5]));
+}
+
+#[test]
+fn breadth_first()
+{
+    let arena = diamond_with_cycle();
+    assert_eq!(visits(&arena, Order::BreadthFirst), [
+        (0, 0, None),
+        (1, 1, Some(0)),
+        (2, 1, Some(1)),
+        (3, 2, Some(0)),
+        (4, 2, Some(1)),
+        (5, 2, Some(0)),
+        (6, 3, Some(0)),
+    ]);
+}
+
+#[test]
+fn paths()
+{
+    let arena = diamond_with_cycle();
+    let mut path = Vec::new();
+    let paths: Vec<_> = traverse(My(&arena, 0))
+        .map(|v| {
+            path.truncate(v.depth.saturating_sub(1));
+            path.extend(v.index);
+            (v.node.1, path.clone())
+        })
+        .collect();
+    assert_eq!(paths, [
+        (0, vec![]),
+        (1, vec![0]),
+        (3, vec![0, 0]),
+        (4, vec![0, 1]),
+        (6, vec![0, 1, 0]),
+        (2, vec![1]),
+        (5, vec![1, 0]),
+    ]);
+}
+
+#[test]
+fn degenerate_cyclic()
+{
+    const DEPTH: usize = 100;
+
+    let mut nodes: Vec<_> = (0 .. DEPTH).map(|i| vec![i + 1, i + 1]).collect();
+    nodes.push(vec![0, 0]);
+    let arena = Arena::from_edges(nodes);
+
+    for order in [Order::DepthFirst, Order::BreadthFirst] {
+        let visited = Traverse::new(My(&arena, 0), order).map(|v| v.node.1);
+        assert!(visited.eq(0 ..= DEPTH));
+    }
+}
+
+#[test]
+fn long()
+{
+    const LENGTH: usize = 1_000_000;
+
+    let arena = Arena::from_edges((0 .. LENGTH).map(|i| vec![i + 1]).chain([vec![]]).collect());
+
+    for order in [Order::DepthFirst, Order::BreadthFirst] {
+        let last = Traverse::new(My(&arena, 0), order).last().unwrap();
+        assert_eq!((last.node.1, last.depth), (LENGTH, LENGTH));
+    }
+}
+
+#[test]
+fn leaf()
+{
+    let arena = Arena::from_edges(vec![vec![]]);
+    assert_eq!(visits(&arena, Order::DepthFirst), [(0, 0, None)]);
+    assert_eq!(visits(&arena, Order::BreadthFirst), [(0, 0, None)]);
+}
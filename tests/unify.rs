@@ -0,0 +1,210 @@
+#![cfg(feature = "std")]
+
+use {
+    graph_safe_compare::unify::{
+        unify,
+        Clash,
+        Substitution,
+        Unifiable,
+        Unifier,
+    },
+    std::collections::HashMap,
+    tests_utils::node_types::arena::Arena,
+};
+
+tests_utils::arena_node!();
+
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Kind
+{
+    Var(char),
+    Con(&'static str),
+}
+
+impl My<'_, Kind>
+{
+    fn kind(&self) -> Kind
+    {
+        *self.value()
+    }
+}
+
+impl Unifiable for My<'_, Kind>
+{
+    type Var = char;
+
+    fn as_variable(&self) -> Option<Self::Var>
+    {
+        match self.kind() {
+            Kind::Var(v) => Some(v),
+            Kind::Con(_) => None,
+        }
+    }
+}
+
+fn var(v: char) -> (Kind, Vec<usize>)
+{
+    (Kind::Var(v), vec![])
+}
+
+fn con(
+    name: &'static str,
+    edges: &[usize],
+) -> (Kind, Vec<usize>)
+{
+    (Kind::Con(name), edges.to_vec())
+}
+
+/// The indexes of the nodes that the variables are bound to.
+fn indexes(substitution: &Substitution<My<'_, Kind>>) -> HashMap<char, usize>
+{
+    substitution.iter().map(|(&v, node)| (v, node.1)).collect()
+}
+
+fn clash_indexes(clash: &Clash<My<'_, Kind>>) -> (usize, usize)
+{
+    (clash.a.1, clash.b.1)
+}
+
+
+#[test]
+fn terms()
+{
+    // f(X, b) = f(a, Y)
+    let arena = Arena::new(vec![
+        con("f", &[1, 2]),
+        var('X'),
+        con("b", &[]),
+        con("f", &[4, 5]),
+        con("a", &[]),
+        var('Y'),
+    ]);
+    let substitution = unify(My(&arena, 0), My(&arena, 3)).unwrap();
+    assert_eq!(indexes(&substitution), HashMap::from([('X', 4), ('Y', 2)]));
+}
+
+#[test]
+fn clash()
+{
+    // f(X, a) = f(b, b)
+    let arena = Arena::new(vec![
+        con("f", &[1, 2]),
+        var('X'),
+        con("a", &[]),
+        con("f", &[4, 4]),
+        con("b", &[]),
+    ]);
+    let clash = unify(My(&arena, 0), My(&arena, 3)).unwrap_err();
+    assert_eq!(clash_indexes(&clash), (2, 4));
+
+    // f(a) = f(a, a)
+    let arena = Arena::new(vec![con("f", &[2]), con("f", &[2, 2]), con("a", &[])]);
+    let clash = unify(My(&arena, 0), My(&arena, 1)).unwrap_err();
+    assert_eq!(clash_indexes(&clash), (0, 1));
+}
+
+#[test]
+fn variables()
+{
+    // f(X, X, Z) = f(Y, a, Y)
+    let arena = Arena::new(vec![
+        con("f", &[1, 1, 2]),
+        var('X'),
+        var('Z'),
+        con("f", &[4, 5, 4]),
+        var('Y'),
+        con("a", &[]),
+    ]);
+    let substitution = unify(My(&arena, 0), My(&arena, 3)).unwrap();
+    assert_eq!(indexes(&substitution), HashMap::from([('X', 5), ('Y', 5), ('Z', 5)]));
+
+    // f(X) = f(Y)
+    let arena = Arena::new(vec![con("f", &[1]), var('X'), con("f", &[3]), var('Y')]);
+    let substitution = unify(My(&arena, 0), My(&arena, 2)).unwrap();
+    assert_eq!(substitution.len(), 1);
+    let (v, node) = substitution.iter().next().unwrap();
+    assert!(matches!((v, node.kind()), ('X', Kind::Var('Y')) | ('Y', Kind::Var('X'))));
+
+    // The same variable.
+    let arena = Arena::new(vec![var('X'), var('X')]);
+    assert!(unify(My(&arena, 0), My(&arena, 1)).unwrap().is_empty());
+}
+
+#[test]
+fn cyclic()
+{
+    // X = f(X), without an occurs check.
+    let arena = Arena::new(vec![var('X'), con("f", &[0])]);
+    let substitution = unify(My(&arena, 0), My(&arena, 1)).unwrap();
+    assert_eq!(indexes(&substitution), HashMap::from([('X', 1)]));
+
+    // A = f(A) and B = f(f(B)) are the same rational term.
+    let arena = Arena::new(vec![con("f", &[0]), con("f", &[2]), con("f", &[1])]);
+    assert!(unify(My(&arena, 0), My(&arena, 1)).unwrap().is_empty());
+
+    // A = list(int, A) and B = list(X, list(Y, B))
+    let arena = Arena::new(vec![
+        con("list", &[1, 0]),
+        con("int", &[]),
+        con("list", &[3, 4]),
+        var('X'),
+        con("list", &[5, 2]),
+        var('Y'),
+    ]);
+    let substitution = unify(My(&arena, 0), My(&arena, 2)).unwrap();
+    assert_eq!(indexes(&substitution), HashMap::from([('X', 1), ('Y', 1)]));
+
+    // A = list(int, A) and B = list(int, list(bool, B))
+    let arena = Arena::new(vec![
+        con("list", &[1, 0]),
+        con("int", &[]),
+        con("list", &[1, 3]),
+        con("list", &[4, 2]),
+        con("bool", &[]),
+    ]);
+    let clash = unify(My(&arena, 0), My(&arena, 2)).unwrap_err();
+    assert_eq!(clash_indexes(&clash), (1, 4));
+}
+
+#[test]
+fn deep()
+{
+    const DEPTH: usize = 100_000;
+
+    // f(f(...f(X)...)) = f(f(...f(a)...))
+    let mut nodes: Vec<_> = (0 .. DEPTH).map(|i| con("f", &[i + 1])).collect();
+    nodes.push(var('X'));
+    nodes.extend((0 .. DEPTH).map(|i| con("f", &[DEPTH + 1 + i + 1])));
+    nodes.push(con("a", &[]));
+    let arena = Arena::new(nodes);
+
+    let substitution = unify(My(&arena, 0), My(&arena, DEPTH + 1)).unwrap();
+    assert_eq!(indexes(&substitution), HashMap::from([('X', 2 * DEPTH + 1)]));
+}
+
+#[test]
+fn unifier()
+{
+    let arena = Arena::new(vec![
+        var('X'),
+        var('Y'),
+        con("a", &[]),
+        con("b", &[]),
+        con("f", &[0]),
+        var('Z'),
+    ]);
+    let node = |i| My(&arena, i);
+    let mut unifier = Unifier::new();
+
+    unifier.unify(node(0), node(1)).unwrap();
+    unifier.unify(node(1), node(2)).unwrap();
+    assert_eq!(unifier.resolve(node(0)).1, 2);
+    unifier.unify(node(5), node(4)).unwrap();
+    assert_eq!(unifier.resolve(node(5)).1, 4);
+    assert_eq!(unifier.resolve(node(3)).1, 3);
+    assert_eq!(indexes(&unifier.substitution()), HashMap::from([('X', 2), ('Y', 2), ('Z', 4)]));
+
+    let clash = unifier.unify(node(0), node(3)).unwrap_err();
+    assert_eq!(clash_indexes(&clash), (2, 3));
+}
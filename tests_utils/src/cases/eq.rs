@@ -449,6 +449,61 @@ macro_rules! eq_variations_tests
                 $crate::eq_shapes_tests!($alloc_trans, $make_alloc, MyEq::new,
                                          #[ignore], #[cfg(all())]);
             }
+
+            /// Like `wide_safe::equiv` but with the recursion mode that uses the call-stack up to
+            /// a limited depth.
+            fn hybrid_equiv<N: graph_safe_compare::Node>(
+                a: N,
+                b: N,
+            ) -> bool
+            {
+                use {
+                    graph_safe_compare::{
+                        basic::modes::unlimited::Unlimited,
+                        generic::equiv::{
+                            self,
+                            Equiv,
+                        },
+                        wide_safe::recursion::hybrid::{
+                            self,
+                            Hybrid,
+                        },
+                        Cmp as _,
+                    },
+                    core::{
+                        convert::Infallible,
+                        marker::PhantomData,
+                    },
+                };
+
+                struct Args<N>(PhantomData<N>);
+
+                impl<N: Node> equiv::Params for Args<N>
+                {
+                    type DescendMode = Unlimited;
+                    type Error = Infallible;
+                    type Node = N;
+                    type RecurMode = Hybrid<Self>;
+                }
+
+                impl<N: Node> hybrid::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                let cmp = Equiv::<Args<_>>::default().equiv(a, b).unwrap();
+                cmp.is_equiv()
+            }
+
+            mod hybrid
+            {
+                $crate::eq_variation_mod_body!(
+                    super::hybrid_equiv,
+                    $my_type, $datum_type, $alloc_trans, $make_alloc);
+
+                $crate::eq_shapes_tests!($alloc_trans, $make_alloc, MyEq::new,
+                                         #[ignore], #[cfg(all())]);
+            }
         }
 
         #[cfg(test)]
@@ -562,6 +617,236 @@ macro_rules! eq_variations_tests
                 $crate::eq_shapes_tests!($alloc_trans, $make_alloc, MyEq::new,
                                          #[cfg(all())], #[cfg(all())]);
             }
+
+            /// Like `robust::equiv` but with the fast hasher of the IDs instead of the default.
+            fn id_hasher_interleave_equiv<N: graph_safe_compare::Node>(
+                a: N,
+                b: N,
+            ) -> bool
+            {
+                use {
+                    graph_safe_compare::{
+                        cycle_safe::modes::interleave::{
+                            self,
+                            random::default,
+                            Interleave,
+                        },
+                        deep_safe::recursion::{
+                            self,
+                            queue::RecurQueue,
+                        },
+                        generic::{
+                            equiv::{
+                                self,
+                                Equiv,
+                            },
+                            equiv_classes::premade::{
+                                hash_map,
+                                id_hasher::BuildIdHasher,
+                            },
+                        },
+                        Cmp as _,
+                    },
+                    core::{
+                        convert::Infallible,
+                        marker::PhantomData,
+                    },
+                };
+
+                struct Args<N>(PhantomData<N>);
+
+                impl<N: Node> equiv::Params for Args<N>
+                {
+                    type DescendMode = Interleave<Self>;
+                    type Error = Infallible;
+                    type Node = N;
+                    type RecurMode = RecurQueue<Self>;
+                }
+
+                impl<N: Node> recursion::queue::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                impl<N: Node> hash_map::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                impl<N: Node> interleave::Params for Args<N>
+                {
+                    type Node = N;
+                    // Use a custom hasher, not the default.
+                    type Table = hash_map::Table<Self, BuildIdHasher>;
+                    type RNG = default::RandomNumberGenerator;
+                }
+
+                let cmp = Equiv::<Args<_>>::default().equiv(a, b).unwrap();
+                cmp.is_equiv()
+            }
+
+            mod id_hasher_interleave_recurqueue
+            {
+                $crate::eq_variation_mod_body!(
+                    super::id_hasher_interleave_equiv,
+                    $my_type, $datum_type, $alloc_trans, $make_alloc);
+
+                $crate::eq_shapes_tests!($alloc_trans, $make_alloc, MyEq::new,
+                                         #[cfg(all())], #[cfg(all())]);
+            }
+
+            /// Like `robust::equiv` but with the table that stores the classes contiguously.
+            fn arena_interleave_equiv<N: graph_safe_compare::Node>(
+                a: N,
+                b: N,
+            ) -> bool
+            {
+                use {
+                    graph_safe_compare::{
+                        cycle_safe::modes::interleave::{
+                            self,
+                            random::default,
+                            Interleave,
+                        },
+                        deep_safe::recursion::{
+                            self,
+                            queue::RecurQueue,
+                        },
+                        generic::{
+                            equiv::{
+                                self,
+                                Equiv,
+                            },
+                            equiv_classes::premade::arena,
+                        },
+                        Cmp as _,
+                    },
+                    core::{
+                        convert::Infallible,
+                        marker::PhantomData,
+                    },
+                };
+
+                struct Args<N>(PhantomData<N>);
+
+                impl<N: Node> equiv::Params for Args<N>
+                {
+                    type DescendMode = Interleave<Self>;
+                    type Error = Infallible;
+                    type Node = N;
+                    type RecurMode = RecurQueue<Self>;
+                }
+
+                impl<N: Node> recursion::queue::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                impl<N: Node> arena::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                impl<N: Node> interleave::Params for Args<N>
+                {
+                    type Node = N;
+                    type Table = arena::Table<Self>;
+                    type RNG = default::RandomNumberGenerator;
+                }
+
+                let cmp = Equiv::<Args<_>>::default().equiv(a, b).unwrap();
+                cmp.is_equiv()
+            }
+
+            mod arena_interleave_recurqueue
+            {
+                $crate::eq_variation_mod_body!(
+                    super::arena_interleave_equiv,
+                    $my_type, $datum_type, $alloc_trans, $make_alloc);
+
+                $crate::eq_shapes_tests!($alloc_trans, $make_alloc, MyEq::new,
+                                         #[cfg(all())], #[cfg(all())]);
+            }
+
+            /// Like `robust::equiv` but with the mode that adapts its limits.
+            fn adaptive_equiv<N: graph_safe_compare::Node>(
+                a: N,
+                b: N,
+            ) -> bool
+            {
+                use {
+                    graph_safe_compare::{
+                        cycle_safe::modes::{
+                            adaptive::{
+                                self,
+                                Adaptive,
+                            },
+                            interleave::{
+                                self,
+                                random::default,
+                            },
+                        },
+                        deep_safe::recursion::{
+                            self,
+                            queue::RecurQueue,
+                        },
+                        generic::{
+                            equiv::{
+                                self,
+                                Equiv,
+                            },
+                            equiv_classes::premade::hash_map,
+                        },
+                        Cmp as _,
+                    },
+                    core::{
+                        convert::Infallible,
+                        marker::PhantomData,
+                    },
+                };
+
+                struct Args<N>(PhantomData<N>);
+
+                impl<N: Node> equiv::Params for Args<N>
+                {
+                    type DescendMode = Adaptive<Self>;
+                    type Error = Infallible;
+                    type Node = N;
+                    type RecurMode = RecurQueue<Self>;
+                }
+
+                impl<N: Node> recursion::queue::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                impl<N: Node> hash_map::Params for Args<N>
+                {
+                    type Node = N;
+                }
+
+                impl<N: Node> interleave::Params for Args<N>
+                {
+                    type Node = N;
+                    type Table = hash_map::Table<Self>;
+                    type RNG = default::RandomNumberGenerator;
+                }
+
+                impl<N: Node> adaptive::Params for Args<N> {}
+
+                let cmp = Equiv::<Args<_>>::default().equiv(a, b).unwrap();
+                cmp.is_equiv()
+            }
+
+            mod adaptive_recurqueue
+            {
+                $crate::eq_variation_mod_body!(
+                    super::adaptive_equiv,
+                    $my_type, $datum_type, $alloc_trans, $make_alloc);
+
+                $crate::eq_shapes_tests!($alloc_trans, $make_alloc, MyEq::new,
+                                         #[cfg(all())], #[cfg(all())]);
+            }
         }
     };
 }
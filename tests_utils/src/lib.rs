@@ -17,6 +17,7 @@ pub mod node_types
     pub mod diff_index;
     pub mod wide;
     pub mod lazy;
+    pub mod arena;
 }
 
 pub mod shapes;
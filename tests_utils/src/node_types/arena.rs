@@ -0,0 +1,142 @@
+use std::cell::Cell;
+
+
+/// Nodes are elements of a vector, with values, and edges are indexes of other elements.
+#[derive(Debug)]
+pub struct Arena<V = i32>
+{
+    pub nodes:  Vec<(V, Vec<usize>)>,
+    /// Counts how many times nodes are compared.
+    pub visits: Cell<u64>,
+}
+
+impl<V> Arena<V>
+{
+    pub fn new(nodes: Vec<(V, Vec<usize>)>) -> Self
+    {
+        Self { nodes, visits: Cell::new(0) }
+    }
+
+    pub fn value(
+        &self,
+        index: usize,
+    ) -> &V
+    {
+        &self.nodes[index].0
+    }
+
+    pub fn edges(
+        &self,
+        index: usize,
+    ) -> &[usize]
+    {
+        &self.nodes[index].1
+    }
+
+    /// Count a comparison of nodes.
+    pub fn visit(&self)
+    {
+        self.visits.set(self.visits.get() + 1);
+    }
+}
+
+impl<V: Clone> Arena<V>
+{
+    /// A list of `len` nodes that all have the same value.
+    pub fn list(
+        len: usize,
+        value: V,
+    ) -> Self
+    {
+        Self::new(
+            (0 .. len)
+                .map(|i| (value.clone(), if i + 1 < len { vec![i + 1] } else { vec![] }))
+                .collect(),
+        )
+    }
+
+    /// A chain of `len` nodes that each have two edges to the next, and the last to the first.
+    pub fn degenerate_cyclic(
+        len: usize,
+        value: V,
+    ) -> Self
+    {
+        Self::new(
+            (0 .. len).map(|i| (value.clone(), vec![(i + 1) % len, (i + 1) % len])).collect(),
+        )
+    }
+}
+
+impl Arena<()>
+{
+    /// Nodes that only have edges, and so are all equivalent modulo edges.
+    pub fn from_edges(nodes: Vec<Vec<usize>>) -> Self
+    {
+        Self::new(nodes.into_iter().map(|edges| ((), edges)).collect())
+    }
+}
+
+
+/// Define `My`, the [`Arena`] node type, in the invoking crate.
+///
+/// It is defined by a macro, instead of here, so that this crate does not depend on
+/// `graph_safe_compare`, and so that tests can implement that crate's other traits for it.
+#[macro_export]
+macro_rules! arena_node {
+    () => {
+        /// A node of an [`Arena`](tests_utils::node_types::arena::Arena), by its index.
+        #[derive(Debug)]
+        pub struct My<'l, V = i32>(pub &'l $crate::node_types::arena::Arena<V>, pub usize);
+
+        // Not derived, because that would require `V: Copy`.
+        impl<V> Copy for My<'_, V> {}
+
+        impl<V> Clone for My<'_, V>
+        {
+            fn clone(&self) -> Self
+            {
+                *self
+            }
+        }
+
+        impl<'l, V> My<'l, V>
+        {
+            pub fn value(&self) -> &'l V
+            {
+                self.0.value(self.1)
+            }
+        }
+
+        impl<'l, V: PartialEq> graph_safe_compare::Node for My<'l, V>
+        {
+            type Cmp = bool;
+            type Id = (
+                graph_safe_compare::utils::RefId<&'l $crate::node_types::arena::Arena<V>>,
+                usize,
+            );
+            type Index = usize;
+
+            fn id(&self) -> Self::Id
+            {
+                (graph_safe_compare::utils::RefId(self.0), self.1)
+            }
+
+            fn get_edge(
+                &self,
+                index: &Self::Index,
+            ) -> Option<Self>
+            {
+                self.0.edges(self.1).get(*index).map(|&i| My(self.0, i))
+            }
+
+            fn equiv_modulo_edges(
+                &self,
+                other: &Self,
+            ) -> Self::Cmp
+            {
+                self.0.visit();
+                self.value() == other.value()
+            }
+        }
+    };
+}